@@ -0,0 +1,62 @@
+//! Criterion benchmarks for the parse and solve phases of every day, run
+//! against each crate's embedded input with `cargo bench -p aoc`.
+
+use aoc_common::{Part, Solution};
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::hint::black_box;
+
+/// Benchmarks parsing `input` and then each of `parts`, in a group named
+/// after the crate.
+fn bench_solution<S: Solution>(c: &mut Criterion, crate_name: &str, input: &str, parts: &[Part]) {
+    let mut group = c.benchmark_group(crate_name);
+
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(input))));
+
+    let parsed = S::parse(input).expect("The embedded input should parse.");
+
+    for &part in parts {
+        group.bench_function(format!("part {part}"), |b| {
+            b.iter(|| S::solve(black_box(&parsed), part))
+        });
+    }
+
+    group.finish();
+}
+
+/// Benchmarks a part that takes too long to sample the usual hundred times,
+/// taking as few samples as criterion allows instead.
+fn bench_slow_part<S: Solution>(c: &mut Criterion, crate_name: &str, input: &str, part: Part) {
+    let mut group = c.benchmark_group(crate_name);
+    group.sample_size(10);
+
+    let parsed = S::parse(input).expect("The embedded input should parse.");
+
+    group.bench_function(format!("part {part}"), |b| {
+        b.iter(|| S::solve(black_box(&parsed), part))
+    });
+
+    group.finish();
+}
+
+fn solvers(c: &mut Criterion) {
+    bench_solution::<day_1::Day1>(c, "day_1", day_1::INPUT, &[Part::One]);
+    bench_solution::<day_1_p_2::Day1>(c, "day_1_p_2", day_1_p_2::INPUT, &[Part::Two]);
+    bench_solution::<day_2::Day2>(c, "day_2", day_2::INPUT, &[Part::One, Part::Two]);
+    bench_solution::<day_3::Day3>(c, "day_3", day_3::INPUT, &[Part::One, Part::Two]);
+    bench_solution::<day_4::Day4>(c, "day_4", day_4::INPUT, &[Part::One, Part::Two]);
+    // Part 2 takes every one of a couple of billion seeds through the maps,
+    // which takes a minute or more a run. Skip it with `cargo bench -p aoc --
+    // --skip "day_5/part 2"`.
+    bench_solution::<day_5::Day5>(c, "day_5", day_5::INPUT, &[Part::One]);
+    bench_slow_part::<day_5::Day5>(c, "day_5", day_5::INPUT, Part::Two);
+    bench_solution::<day_6::Day6>(c, "day_6", day_6::INPUT, &[Part::One, Part::Two]);
+    bench_solution::<day_7::Day7>(c, "day_7", day_7::INPUT, &[Part::One]);
+    bench_solution::<day_7_p_2::Day7>(c, "day_7_p_2", day_7_p_2::INPUT, &[Part::Two]);
+    bench_solution::<day_8::Day8>(c, "day_8", day_8::INPUT, &[Part::One]);
+    bench_solution::<day_8_p_2::Day8>(c, "day_8_p_2", day_8_p_2::INPUT, &[Part::Two]);
+    // Part 1 doesn't finish yet, so only parsing is timed.
+    bench_solution::<day_9::Day9>(c, "day_9", day_9::INPUT, &[]);
+}
+
+criterion_group!(benches, solvers);
+criterion_main!(benches);
@@ -0,0 +1,122 @@
+//! The checked-in record of known-good answers, so that a refactor that changes
+//! an answer gets caught by `aoc verify`. Answers are keyed by the SHA-256 of the
+//! input they were computed from, since everyone's input is different.
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fmt::Write;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Where the answers live by default, at the root of the workspace.
+pub fn default_path() -> PathBuf {
+    crate::workspace_root().join("answers.json")
+}
+
+/// Hashes a puzzle input into the hex string used as its key.
+pub fn hash_input(input: &str) -> String {
+    let digest = Sha256::digest(input.as_bytes());
+
+    digest
+        .iter()
+        .fold(String::with_capacity(64), |mut hex, byte| {
+            write!(hex, "{byte:02x}").unwrap();
+            hex
+        })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KnownAnswer {
+    pub day: u8,
+    pub part: u8,
+    pub input_sha256: String,
+    pub answer: String,
+}
+
+#[derive(Debug)]
+pub struct AnswerBook {
+    path: PathBuf,
+    answers: Vec<KnownAnswer>,
+}
+
+impl AnswerBook {
+    /// Loads the answers from `path`. A file that doesn't exist yet is treated
+    /// as having no answers in it.
+    pub fn load(path: PathBuf) -> io::Result<Self> {
+        let answers = match fs::read_to_string(&path) {
+            Ok(raw) => serde_json::from_str(&raw).map_err(io::Error::other)?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Vec::new(),
+            Err(e) => return Err(e),
+        };
+
+        Ok(Self { path, answers })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn get(&self, day: u8, part: u8, input_sha256: &str) -> Option<&str> {
+        self.answers
+            .iter()
+            .find(|known| {
+                known.day == day && known.part == part && known.input_sha256 == input_sha256
+            })
+            .map(|known| known.answer.as_str())
+    }
+
+    /// Records an answer, replacing any answer already known for the same input.
+    pub fn record(&mut self, known_answer: KnownAnswer) {
+        self.answers.retain(|known| {
+            (known.day, known.part, &known.input_sha256)
+                != (
+                    known_answer.day,
+                    known_answer.part,
+                    &known_answer.input_sha256,
+                )
+        });
+
+        self.answers.push(known_answer);
+
+        // We keep the file sorted so that recording an answer makes a small diff.
+        self.answers.sort_by(|a, b| {
+            (a.day, a.part, &a.input_sha256).cmp(&(b.day, b.part, &b.input_sha256))
+        });
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let mut raw = serde_json::to_string_pretty(&self.answers).map_err(io::Error::other)?;
+        raw.push('\n');
+
+        fs::write(&self.path, raw)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_record_replaces_answer() {
+        let mut book = AnswerBook {
+            path: PathBuf::new(),
+            answers: Vec::new(),
+        };
+
+        let hash = hash_input("1abc2");
+
+        for answer in ["11", "12"] {
+            book.record(KnownAnswer {
+                day: 1,
+                part: 1,
+                input_sha256: hash.clone(),
+                answer: answer.to_string(),
+            });
+        }
+
+        assert_eq!(book.get(1, 1, &hash), Some("12"));
+        assert_eq!(book.get(1, 2, &hash), None);
+        assert_eq!(book.answers.len(), 1);
+    }
+}
@@ -0,0 +1,182 @@
+//! `aoc bench`, a quick table of how long each day and part takes. For proper
+//! statistics, use the criterion benchmarks with `cargo bench -p aoc`.
+
+use crate::answers::{self, AnswerBook};
+use crate::output::format_bytes;
+use crate::registry::{self, Outcome, Solver};
+use aoc_common::solution::{Memory, Timings};
+use aoc_common::{memory, Progress};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Duration;
+
+/// Picks the solvers to benchmark, every one of them when no day is given.
+fn select_solvers(day: Option<u8>, part: Option<u8>) -> Vec<&'static Solver> {
+    registry::SOLVERS
+        .iter()
+        .filter(|solver| match day {
+            Some(day) => solver.day == day && part.is_none_or(|part| solver.part.number() == part),
+            None => true,
+        })
+        .collect()
+}
+
+/// Whether there's a known answer for the solver's input. When no day is
+/// given we only time the solvers that have one, since a solver that has
+/// never given a right answer might not finish at all (like day 9 part 1).
+fn has_known_answer(solver: &Solver, book: &AnswerBook) -> bool {
+    book.get(
+        solver.day,
+        solver.part.number(),
+        &answers::hash_input(solver.input),
+    )
+    .is_some()
+}
+
+/// A column pairing the parse and solve phases, like `1.2 KiB / 3.4 MiB`.
+fn phases(memory: &Memory, show: impl Fn(&memory::Stats) -> String) -> String {
+    format!("{} / {}", show(&memory.parse), show(&memory.solve))
+}
+
+/// The middle of `durations`, or the mean of the two middle ones.
+fn median(mut durations: Vec<Duration>) -> Duration {
+    durations.sort();
+
+    let middle = durations.len() / 2;
+
+    match durations.len() % 2 {
+        0 => (durations[middle - 1] + durations[middle]) / 2,
+        _ => durations[middle],
+    }
+}
+
+pub fn bench(day: Option<u8>, part: Option<u8>, samples: usize, answers_path: PathBuf) -> ExitCode {
+    if part.is_some() && day.is_none() {
+        eprintln!("`--part` needs a `--day` to go with it.");
+        return ExitCode::FAILURE;
+    }
+
+    let book = match AnswerBook::load(answers_path.clone()) {
+        Ok(book) => book,
+        Err(e) => {
+            eprintln!(
+                "Failed to load the known answers from {}: {e}",
+                answers_path.display()
+            );
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let solvers = select_solvers(day, part);
+
+    if solvers.is_empty() {
+        eprintln!("There is nothing to benchmark.");
+        return ExitCode::FAILURE;
+    }
+
+    if cfg!(debug_assertions) {
+        eprintln!(
+            "Warning: this is a debug build, so these times will be much slower than release."
+        );
+    }
+
+    let mut failed = false;
+
+    // The runner only counts allocations when it's built to.
+    let counting = memory::is_counting();
+
+    print!(
+        "{:<5}{:<6}{:>14}{:>14}{:>14}",
+        "Day", "Part", "Parse", "Solve", "Total"
+    );
+
+    match counting {
+        true => println!(
+            "{:>24}{:>20}{:>24}{:>12}",
+            "Peak", "Allocations", "Allocated", "Leaked"
+        ),
+        false => println!(),
+    }
+
+    for solver in solvers {
+        if day.is_none() && !has_known_answer(solver, &book) {
+            println!(
+                "{:<5}{:<6}skipped without a known answer",
+                solver.day, solver.part
+            );
+            continue;
+        }
+
+        let mut timings = Vec::with_capacity(samples);
+        let mut problem = None;
+
+        for _ in 0..samples.max(1) {
+            match solver.run_catching_panics(solver.input, &Progress::new()) {
+                Outcome::Solved(_, sample) => timings.push(sample),
+                Outcome::ParseFailed(e) => problem = Some(e.to_string()),
+                Outcome::Panicked(message) => problem = Some(format!("panicked: {message}")),
+                Outcome::Cancelled(reason) => problem = Some(reason.to_string()),
+                Outcome::Unsolved => problem = Some("not solved yet".to_string()),
+            }
+
+            if problem.is_some() {
+                break;
+            }
+        }
+
+        if let Some(problem) = problem {
+            println!("{:<5}{:<6}{problem}", solver.day, solver.part);
+            failed = true;
+            continue;
+        }
+
+        let parse = median(timings.iter().map(|sample| sample.parse).collect());
+        let solve = median(timings.iter().map(|sample| sample.solve).collect());
+        let total = median(timings.iter().map(Timings::total).collect());
+
+        print!(
+            "{:<5}{:<6}{:>14}{:>14}{:>14}",
+            solver.day,
+            solver.part,
+            format!("{parse:.2?}"),
+            format!("{solve:.2?}"),
+            format!("{total:.2?}")
+        );
+
+        // Every run allocates the same, so any of them will do.
+        match timings.last().and_then(|sample| sample.memory) {
+            Some(memory) => println!(
+                "{:>24}{:>20}{:>24}{:>12}",
+                phases(&memory, |stats| format_bytes(stats.peak)),
+                phases(&memory, |stats| stats.allocations.to_string()),
+                phases(&memory, |stats| format_bytes(stats.bytes)),
+                format_bytes(memory.leaked)
+            ),
+            None => println!(),
+        }
+    }
+
+    println!("\nMedian of {} runs each.", samples.max(1));
+
+    if counting {
+        println!("Memory is shown as parse / solve.");
+    }
+
+    match failed {
+        true => ExitCode::FAILURE,
+        false => ExitCode::SUCCESS,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_median() {
+        let millis = |raw: &[u64]| raw.iter().map(|&ms| Duration::from_millis(ms)).collect();
+
+        assert_eq!(median(millis(&[5, 1, 3])), Duration::from_millis(3));
+        assert_eq!(median(millis(&[4, 1, 2, 8])), Duration::from_millis(3));
+    }
+}
@@ -0,0 +1,371 @@
+//! Running a part in a child process, so that a part that panics, never
+//! finishes or eats all the memory can't take the runner down with it.
+
+use crate::registry::Solver;
+use crate::run::{TIMED_OUT_EXIT_CODE, UNSOLVED_EXIT_CODE};
+use serde::Deserialize;
+use std::io::{self, Read};
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// How long past its timeout a part has to stop on its own before it's
+/// killed.
+const KILL_GRACE: Duration = Duration::from_secs(2);
+
+/// What a child process is allowed to use before it's stopped.
+#[derive(Debug, Clone, Copy)]
+pub struct Limits {
+    pub timeout: Duration,
+    /// The most memory the child can reserve, in bytes. Only Unix can limit
+    /// this, so it's ignored elsewhere.
+    pub memory: Option<u64>,
+}
+
+/// What a single run of a part came to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PartRun {
+    Solved {
+        answer: String,
+        parse: Duration,
+        solve: Duration,
+    },
+    /// The part exited without an answer, with the last thing it printed.
+    Failed(String),
+    TimedOut,
+    /// The part hasn't been solved yet.
+    Unsolved,
+}
+
+impl PartRun {
+    pub fn answer(&self) -> String {
+        match self {
+            Self::Solved { answer, .. } => answer.clone(),
+            Self::Failed(message) => format!("failed: {message}"),
+            Self::TimedOut => "timed out".to_string(),
+            Self::Unsolved => "not solved yet".to_string(),
+        }
+    }
+
+    pub fn time(&self, timeout: Duration) -> String {
+        match self {
+            Self::Solved { parse, solve, .. } => format!("{:.2?}", *parse + *solve),
+            Self::Failed(_) | Self::Unsolved => String::new(),
+            Self::TimedOut => format!("> {timeout:.0?}"),
+        }
+    }
+}
+
+/// The fields of `aoc run --format json` that we need.
+#[derive(Deserialize)]
+struct Report {
+    answer: String,
+    parse_ns: u64,
+    solve_ns: u64,
+}
+
+/// How a child process ended, along with everything it printed.
+pub enum Exit {
+    Finished {
+        success: bool,
+        /// The code it exited with, if it wasn't killed by a signal.
+        code: Option<i32>,
+        stdout: String,
+        stderr: String,
+    },
+    TimedOut,
+}
+
+/// Starts a thread that reads all of `pipe`, so a child that prints a lot
+/// can't fill the pipe and block while we're waiting for it.
+fn read_in_background(mut pipe: impl Read + Send + 'static) -> thread::JoinHandle<String> {
+    thread::spawn(move || {
+        let mut text = String::new();
+        let _ = pipe.read_to_string(&mut text);
+        text
+    })
+}
+
+/// Makes an allocation past `bytes` fail in the child, which aborts it.
+#[cfg(unix)]
+fn limit_memory(command: &mut Command, bytes: u64) {
+    use std::os::unix::process::CommandExt;
+
+    let limit = libc::rlimit {
+        rlim_cur: bytes as libc::rlim_t,
+        rlim_max: bytes as libc::rlim_t,
+    };
+
+    // SAFETY: `setrlimit` is async-signal-safe, so it can be called between
+    // forking and exec-ing, and `limit` was made before forking.
+    unsafe {
+        command.pre_exec(move || match libc::setrlimit(libc::RLIMIT_AS, &limit) {
+            0 => Ok(()),
+            _ => Err(io::Error::last_os_error()),
+        });
+    }
+}
+
+#[cfg(not(unix))]
+fn limit_memory(_command: &mut Command, _bytes: u64) {}
+
+/// Runs `command` to the end, killing it if it's still going after
+/// `limits.timeout`.
+pub fn run_with_limits(command: &mut Command, limits: Limits) -> io::Result<Exit> {
+    if let Some(bytes) = limits.memory {
+        limit_memory(command, bytes);
+    }
+
+    let mut child = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    let stdout = read_in_background(child.stdout.take().expect("stdout is piped"));
+    let stderr = read_in_background(child.stderr.take().expect("stderr is piped"));
+
+    let started = Instant::now();
+
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+
+        if started.elapsed() >= limits.timeout {
+            child.kill()?;
+            child.wait()?;
+
+            return Ok(Exit::TimedOut);
+        }
+
+        thread::sleep(Duration::from_millis(10));
+    };
+
+    Ok(Exit::Finished {
+        success: status.success(),
+        code: status.code(),
+        stdout: stdout.join().unwrap_or_default(),
+        stderr: stderr.join().unwrap_or_default(),
+    })
+}
+
+/// Runs one part of a day with `runner`, a build of this binary, and reads
+/// the answer it prints. The part solves `input` if there is one, or else its
+/// embedded input.
+///
+/// A part that checks in with its progress stops itself once the timeout has
+/// passed. Any other part is killed a little after that.
+pub fn run_part(runner: &Path, solver: &Solver, input: Option<&Path>, limits: Limits) -> PartRun {
+    match run_part_exit(runner, solver, input, limits) {
+        Ok(Exit::Finished {
+            success: true,
+            stdout,
+            ..
+        }) => match serde_json::from_str::<Report>(stdout.trim()) {
+            Ok(report) => PartRun::Solved {
+                answer: report.answer,
+                parse: Duration::from_nanos(report.parse_ns),
+                solve: Duration::from_nanos(report.solve_ns),
+            },
+            Err(e) => PartRun::Failed(format!("couldn't read its output: {e}")),
+        },
+        Ok(Exit::Finished { code, .. }) if code == Some(TIMED_OUT_EXIT_CODE.into()) => {
+            PartRun::TimedOut
+        }
+        Ok(Exit::Finished { code, .. }) if code == Some(UNSOLVED_EXIT_CODE.into()) => {
+            PartRun::Unsolved
+        }
+        Ok(Exit::Finished { stderr, .. }) => PartRun::Failed(failure_message(&stderr)),
+        Ok(Exit::TimedOut) => PartRun::TimedOut,
+        Err(e) => PartRun::Failed(format!("couldn't start the runner: {e}")),
+    }
+}
+
+/// Runs one part of a day like [`run_part`], but gives back how the child
+/// exited, with the whole JSON report it printed, instead of just the answer.
+pub fn run_part_exit(
+    runner: &Path,
+    solver: &Solver,
+    input: Option<&Path>,
+    limits: Limits,
+) -> io::Result<Exit> {
+    let mut command = Command::new(runner);
+    // Traces would get in the way of reading why a part failed.
+    command
+        .env("RUST_BACKTRACE", "0")
+        .env_remove("RUST_LOG")
+        .args([
+            "run",
+            &solver.day.to_string(),
+            "--part",
+            &solver.part.to_string(),
+            "--format",
+            "json",
+            "--timeout",
+            &limits.timeout.as_secs().to_string(),
+        ]);
+
+    if let Some(input) = input {
+        command.arg("--input").arg(input);
+    }
+
+    let limits = Limits {
+        timeout: limits.timeout + KILL_GRACE,
+        ..limits
+    };
+
+    run_with_limits(&mut command, limits)
+}
+
+/// Calls `run` on every item with up to `jobs` at a time, giving back the runs
+/// in the same order as the items.
+pub fn run_concurrently<T: Sync>(
+    items: &[T],
+    jobs: usize,
+    run: impl Fn(&T) -> PartRun + Sync,
+) -> Vec<PartRun> {
+    let next = Mutex::new(0);
+    let runs = Mutex::new(vec![None; items.len()]);
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, items.len().max(1)) {
+            scope.spawn(|| loop {
+                let i = {
+                    let mut next = next.lock().unwrap();
+                    *next += 1;
+                    *next - 1
+                };
+
+                let Some(item) = items.get(i) else {
+                    break;
+                };
+
+                let part_run = run(item);
+                runs.lock().unwrap()[i] = Some(part_run);
+            });
+        }
+    });
+
+    runs.into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|run| run.expect("Every item should have been run."))
+        .collect()
+}
+
+/// What went wrong, from what a failed run printed: the message of a panic,
+/// the first line of a parse error, or otherwise the last line that says
+/// something. Notes about backtraces are skipped.
+pub fn failure_message(stderr: &str) -> String {
+    let lines = stderr
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with("note:"))
+        .collect::<Vec<_>>();
+
+    // A panic's message is on the line after the one saying where it was.
+    let panic_message = lines
+        .iter()
+        .position(|line| line.contains("panicked at"))
+        .and_then(|i| lines.get(i + 1));
+
+    let error = lines.iter().find(|line| line.starts_with("error:"));
+
+    let line = match panic_message.or(error).or(lines.last()) {
+        Some(line) => line,
+        None => "no output",
+    };
+
+    line.to_string()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn limits(timeout: Duration) -> Limits {
+        Limits {
+            timeout,
+            memory: None,
+        }
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_run_with_limits() {
+        let exit = run_with_limits(
+            Command::new("sleep").arg("10"),
+            limits(Duration::from_millis(50)),
+        );
+        assert!(matches!(exit, Ok(Exit::TimedOut)));
+
+        let exit = run_with_limits(
+            Command::new("sh").args(["-c", "echo answer; echo oops >&2; exit 1"]),
+            limits(Duration::from_secs(10)),
+        );
+        let Ok(Exit::Finished {
+            success,
+            code,
+            stdout,
+            stderr,
+        }) = exit
+        else {
+            panic!("the command should have finished");
+        };
+        assert_eq!(
+            (success, code, stdout.as_str(), stderr.as_str()),
+            (false, Some(1), "answer\n", "oops\n")
+        );
+
+        // `ulimit -v` prints the limit in KiB.
+        let exit = run_with_limits(
+            Command::new("sh").args(["-c", "ulimit -v"]),
+            Limits {
+                timeout: Duration::from_secs(10),
+                memory: Some(256 << 20),
+            },
+        );
+        let Ok(Exit::Finished { stdout, .. }) = exit else {
+            panic!("the command should have finished");
+        };
+        assert_eq!(stdout.trim(), (256 << 10).to_string());
+    }
+
+    #[test]
+    fn test_failure_message() {
+        let panic = "\nthread 'main' panicked at day_8_p_2/src/lib.rs:10:5:\n\
+                     attempt to multiply with overflow\n\
+                     note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace\n";
+
+        assert_eq!(failure_message(panic), "attempt to multiply with overflow");
+        assert_eq!(failure_message("error: bad input\n"), "error: bad input");
+        assert_eq!(
+            failure_message("error: day 4 input, line 1\n  |\n1 | Card\n  |     ^\n"),
+            "error: day 4 input, line 1"
+        );
+        assert_eq!(
+            failure_message(
+                "memory allocation of 2533443296 bytes failed\nnote: run with `RUST_BACKTRACE=1`\n"
+            ),
+            "memory allocation of 2533443296 bytes failed"
+        );
+        assert_eq!(failure_message(""), "no output");
+    }
+
+    #[test]
+    fn test_run_concurrently() {
+        let items = (0..10).collect::<Vec<u32>>();
+        let runs = run_concurrently(&items, 3, |i| PartRun::Failed(i.to_string()));
+
+        assert_eq!(
+            runs,
+            items
+                .iter()
+                .map(|i| PartRun::Failed(i.to_string()))
+                .collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,396 @@
+//! `aoc compare`, which runs a day against every input in a directory, so that
+//! everyone's inputs can be checked at once. Each part runs in its own process
+//! (see [`child`]), and inputs that make a part fail, or that look wrong, are
+//! flagged in the table.
+
+use crate::answers::{self, AnswerBook};
+use crate::child::{self, Limits, PartRun};
+use crate::output::Format;
+use crate::registry::Solver;
+use crate::run;
+use crate::run_all::Status;
+use aoc_common::normalise::normalise;
+use serde::Serialize;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+/// The JSON object printed for each input and part with `--format json`.
+#[derive(Debug, Serialize)]
+struct CompareReport {
+    input: String,
+    day: u8,
+    part: u8,
+    status: &'static str,
+    answer: Option<String>,
+    expected: Option<String>,
+    parse_ns: Option<u128>,
+    solve_ns: Option<u128>,
+    /// Why the part failed, if it did.
+    message: Option<String>,
+    /// Anything that looks wrong about the input or the answer.
+    warnings: Vec<String>,
+}
+
+/// One input file, along with the answers known for it.
+struct Input {
+    path: PathBuf,
+    text: String,
+    /// The answers written next to the input by `aoc gen`, if there are any.
+    generated_answers: AnswerBook,
+    /// Anything that looks wrong about the input itself.
+    warnings: Vec<String>,
+}
+
+impl Input {
+    fn name(&self) -> String {
+        self.path
+            .file_name()
+            .unwrap_or(self.path.as_os_str())
+            .to_string_lossy()
+            .into_owned()
+    }
+}
+
+/// Reads every input in `dir`, sorted by name. The `.answers.json` files
+/// `aoc gen` writes are read along with the input they belong to.
+fn read_inputs(solvers: &[&Solver], dir: &Path) -> io::Result<Vec<Input>> {
+    let mut paths = fs::read_dir(dir)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<io::Result<Vec<_>>>()?;
+    paths.retain(|path| path.is_file() && !path.to_string_lossy().ends_with(".answers.json"));
+    paths.sort();
+
+    paths
+        .into_iter()
+        .map(|path| {
+            let text = fs::read_to_string(&path)?;
+            let generated_answers = AnswerBook::load(path.with_extension("answers.json"))?;
+
+            Ok(Input {
+                warnings: check_input(solvers, &text),
+                path,
+                text,
+                generated_answers,
+            })
+        })
+        .collect()
+}
+
+/// Things about an input that the puzzle promises won't happen, but which
+/// the solver would quietly get wrong rather than fail on, along with the
+/// whitespace quirks that had to be tidied up before parsing it.
+fn check_input(solvers: &[&Solver], input: &str) -> Vec<String> {
+    let mut warnings = normalise(input)
+        .quirks
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+
+    for solver in solvers {
+        // Parts that share a parser find the same things.
+        for warning in (solver.check)(input) {
+            if !warnings.contains(&warning) {
+                warnings.push(warning);
+            }
+        }
+    }
+
+    warnings
+}
+
+/// Answers that are most likely wrong whatever the input was.
+fn check_answer(run: &PartRun) -> Option<String> {
+    let PartRun::Solved { answer, .. } = run else {
+        return None;
+    };
+
+    if answer == "0" {
+        Some("the answer is zero".to_string())
+    } else if answer.starts_with('-') {
+        Some("the answer is negative".to_string())
+    } else {
+        None
+    }
+}
+
+/// One run of one part on one input, with everything we found out about it.
+struct Comparison<'a> {
+    input: &'a Input,
+    solver: &'a Solver,
+    run: PartRun,
+    expected: Option<String>,
+    status: Status,
+    /// Anything that looks wrong about the answer.
+    warning: Option<String>,
+}
+
+fn print_table(comparisons: &[Comparison]) {
+    let width = comparisons
+        .iter()
+        .map(|comparison| comparison.input.name().len() + 2)
+        .max()
+        .unwrap_or_default()
+        .max(7);
+
+    println!(
+        "{:<width$}{:<6}{:<11}{:<24}{:>12}{:>12}",
+        "Input", "Part", "Status", "Answer", "Parse", "Solve"
+    );
+
+    for comparison in comparisons {
+        let (answer, parse, solve) = match &comparison.run {
+            PartRun::Solved {
+                answer,
+                parse,
+                solve,
+            } => (
+                answer.clone(),
+                format!("{parse:.2?}"),
+                format!("{solve:.2?}"),
+            ),
+            PartRun::Failed(message) => (message.clone(), String::new(), String::new()),
+            PartRun::TimedOut | PartRun::Unsolved => (String::new(), String::new(), String::new()),
+        };
+
+        let answer = match (&comparison.status, &comparison.expected) {
+            (Status::Wrong, Some(expected)) => format!("{answer} (expected {expected})"),
+            _ => answer,
+        };
+
+        println!(
+            "{:<width$}{:<6}{:<11}{:<24}{:>12}{:>12}",
+            comparison.input.name(),
+            comparison.solver.part,
+            comparison.status.name(),
+            answer,
+            parse,
+            solve
+        );
+    }
+
+    let mut warnings = Vec::new();
+    let mut last_input = None;
+
+    for comparison in comparisons {
+        let input = comparison.input.name();
+
+        // Every part of an input shares its warnings, so they're only shown
+        // once.
+        if last_input.as_ref() != Some(&input) {
+            warnings.extend(
+                comparison
+                    .input
+                    .warnings
+                    .iter()
+                    .map(|warning| format!("{input}: {warning}")),
+            );
+        }
+
+        if let Some(warning) = &comparison.warning {
+            warnings.push(format!(
+                "{input} part {}: {warning}",
+                comparison.solver.part
+            ));
+        }
+
+        last_input = Some(input);
+    }
+
+    if !warnings.is_empty() {
+        println!("\nSuspicious:");
+
+        for warning in warnings {
+            println!("  {warning}");
+        }
+    }
+}
+
+fn print_json(comparisons: &[Comparison]) {
+    for comparison in comparisons {
+        let mut report = CompareReport {
+            input: comparison.input.path.display().to_string(),
+            day: comparison.solver.day,
+            part: comparison.solver.part.number(),
+            status: comparison.status.name(),
+            answer: None,
+            expected: comparison.expected.clone(),
+            parse_ns: None,
+            solve_ns: None,
+            message: None,
+            warnings: comparison
+                .input
+                .warnings
+                .iter()
+                .chain(&comparison.warning)
+                .cloned()
+                .collect(),
+        };
+
+        match &comparison.run {
+            PartRun::Solved {
+                answer,
+                parse,
+                solve,
+            } => {
+                report.answer = Some(answer.clone());
+                report.parse_ns = Some(parse.as_nanos());
+                report.solve_ns = Some(solve.as_nanos());
+            }
+            PartRun::Failed(message) => report.message = Some(message.clone()),
+            PartRun::TimedOut | PartRun::Unsolved => {}
+        }
+
+        println!(
+            "{}",
+            serde_json::to_string(&report).expect("A report should always serialize.")
+        );
+    }
+}
+
+pub fn compare(
+    day: u8,
+    dir: PathBuf,
+    part: Option<u8>,
+    format: Format,
+    limits: Limits,
+    jobs: usize,
+) -> ExitCode {
+    let Some(solvers) = run::select_solvers(day, part) else {
+        return ExitCode::FAILURE;
+    };
+
+    let inputs = match read_inputs(&solvers, &dir) {
+        Ok(inputs) if inputs.is_empty() => {
+            eprintln!("There are no inputs in {}.", dir.display());
+            return ExitCode::FAILURE;
+        }
+        Ok(inputs) => inputs,
+        Err(e) => {
+            eprintln!("Failed to read the inputs in {}: {e}", dir.display());
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let answers_path = answers::default_path();
+    let book = match AnswerBook::load(answers_path.clone()) {
+        Ok(book) => book,
+        Err(e) => {
+            eprintln!(
+                "Failed to load the known answers from {}: {e}",
+                answers_path.display()
+            );
+            return ExitCode::FAILURE;
+        }
+    };
+
+    // The parts run in copies of this binary, so that we can kill them.
+    let runner = match std::env::current_exe() {
+        Ok(runner) => runner,
+        Err(e) => {
+            eprintln!("Failed to find the runner to run the parts with: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let pairs = inputs
+        .iter()
+        .flat_map(|input| solvers.iter().map(move |solver| (input, *solver)))
+        .collect::<Vec<_>>();
+
+    let runs = child::run_concurrently(&pairs, jobs, |(input, solver)| {
+        child::run_part(&runner, solver, Some(&input.path), limits)
+    });
+
+    let comparisons = pairs
+        .into_iter()
+        .zip(runs)
+        .map(|((input, solver), run)| {
+            let hash = answers::hash_input(&input.text);
+            let expected = book
+                .get(day, solver.part.number(), &hash)
+                .or_else(|| {
+                    input
+                        .generated_answers
+                        .get(day, solver.part.number(), &hash)
+                })
+                .map(str::to_string);
+
+            Comparison {
+                input,
+                solver,
+                status: Status::of(&run, expected.as_deref()),
+                warning: check_answer(&run),
+                run,
+                expected,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    match format {
+        Format::Text => print_table(&comparisons),
+        Format::Json => print_json(&comparisons),
+    }
+
+    match comparisons
+        .iter()
+        .any(|comparison| comparison.status.is_failure())
+    {
+        true => ExitCode::FAILURE,
+        false => ExitCode::SUCCESS,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::registry;
+    use std::time::Duration;
+
+    #[test]
+    fn test_check_input() {
+        let day_2 = registry::for_day(2);
+        let day_4 = registry::for_day(4);
+
+        // Card 2 has two matches, but there's only one card after it. Both
+        // parts find that, but it's only said once.
+        let cards = "Card 1: 1 2 | 3 4\n\
+                     Card 2: 1 2 | 1 2\n\
+                     Card 3: 5 6 | 7 8\n";
+
+        assert_eq!(
+            check_input(&day_4, cards),
+            ["card 2 wins copies of the 2 cards after it, but only 1 come after it"]
+        );
+        assert_eq!(check_input(&day_4, ::day_4::INPUT), Vec::<String>::new());
+        assert_eq!(check_input(&day_4, "not cards"), Vec::<String>::new());
+        assert_eq!(
+            check_input(&day_2, "Game 1: 3 blue\r\n\r\n"),
+            [
+                "changed the \\r\\n line endings of 2 lines",
+                "the input ends in 1 blank line"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_check_answer() {
+        let solved = |answer: &str| PartRun::Solved {
+            answer: answer.to_string(),
+            parse: Duration::ZERO,
+            solve: Duration::ZERO,
+        };
+
+        assert_eq!(
+            check_answer(&solved("0")).as_deref(),
+            Some("the answer is zero")
+        );
+        assert_eq!(
+            check_answer(&solved("-5")).as_deref(),
+            Some("the answer is negative")
+        );
+        assert_eq!(check_answer(&solved("42")), None);
+        assert_eq!(check_answer(&PartRun::TimedOut), None);
+    }
+}
@@ -0,0 +1,121 @@
+//! Settings for talking to the Advent of Code site. They come from
+//! `~/.config/aoc/config.toml` (or wherever `AOC_CONFIG` points), and any of
+//! them can be overridden with an environment variable:
+//!
+//! ```toml
+//! session = "53616c7465645f5f..."  # AOC_SESSION
+//! base_url = "https://adventofcode.com"  # AOC_BASE_URL
+//! cache_dir = "/home/me/.cache/aoc"  # AOC_CACHE_DIR
+//! throttle_seconds = 5
+//! ```
+
+use serde::Deserialize;
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// How long to wait between two requests to the site by default, so that we
+/// never hammer it even when fetching every day in a loop.
+const DEFAULT_THROTTLE: Duration = Duration::from_secs(5);
+
+/// The config file as it's written, where everything is optional.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ConfigFile {
+    session: Option<String>,
+    base_url: Option<String>,
+    cache_dir: Option<PathBuf>,
+    throttle_seconds: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    /// The value of the `session` cookie from a logged in browser.
+    pub session: Option<String>,
+    pub base_url: String,
+    /// Where fetched inputs are kept, so each one is only downloaded once.
+    pub cache_dir: PathBuf,
+    /// The least time between two requests to the site.
+    pub throttle: Duration,
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    Read(PathBuf, io::Error),
+    Parse(PathBuf, toml::de::Error),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Read(path, e) => write!(f, "failed to read {}: {e}", path.display()),
+            Self::Parse(path, e) => write!(f, "failed to parse {}: {e}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// The user's config directory, following the XDG convention.
+fn config_home() -> PathBuf {
+    match env::var_os("XDG_CONFIG_HOME") {
+        Some(dir) => PathBuf::from(dir),
+        None => home().join(".config"),
+    }
+}
+
+fn cache_home() -> PathBuf {
+    match env::var_os("XDG_CACHE_HOME") {
+        Some(dir) => PathBuf::from(dir),
+        None => home().join(".cache"),
+    }
+}
+
+fn home() -> PathBuf {
+    env::var_os("HOME").map(PathBuf::from).unwrap_or_default()
+}
+
+/// Reads an environment variable, treating an empty one as unset.
+fn var(name: &str) -> Option<String> {
+    env::var(name).ok().filter(|value| !value.is_empty())
+}
+
+impl Config {
+    /// Loads the config file, if there is one, and applies the environment on
+    /// top of it.
+    pub fn load() -> Result<Self, ConfigError> {
+        let path = var("AOC_CONFIG")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| config_home().join("aoc").join("config.toml"));
+
+        let file = match fs::read_to_string(&path) {
+            Ok(raw) => toml::from_str(&raw).map_err(|e| ConfigError::Parse(path, e))?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => ConfigFile::default(),
+            Err(e) => return Err(ConfigError::Read(path, e)),
+        };
+
+        Ok(Self::from_file(file))
+    }
+
+    fn from_file(file: ConfigFile) -> Self {
+        Self {
+            session: var("AOC_SESSION").or(file.session),
+            base_url: var("AOC_BASE_URL")
+                .or(file.base_url)
+                .unwrap_or_else(|| DEFAULT_BASE_URL.to_string()),
+            cache_dir: var("AOC_CACHE_DIR")
+                .map(PathBuf::from)
+                .or(file.cache_dir)
+                .unwrap_or_else(|| cache_home().join("aoc")),
+            throttle: file
+                .throttle_seconds
+                .map(Duration::from_secs)
+                .unwrap_or(DEFAULT_THROTTLE),
+        }
+    }
+}
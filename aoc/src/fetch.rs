@@ -0,0 +1,113 @@
+//! `aoc fetch`, which downloads a day's puzzle input into its crate. Inputs are
+//! cached, so fetching the same day again doesn't touch the site.
+
+use crate::config::Config;
+use crate::site::{self, Site, SiteError};
+use std::fs;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::SystemTime;
+
+/// Where a day's input goes by default, next to the crate that embeds it.
+pub fn default_output(day: u8) -> PathBuf {
+    crate::workspace_root()
+        .join(format!("day_{day}"))
+        .join("input.txt")
+}
+
+fn cache_path(config: &Config, day: u8) -> PathBuf {
+    config
+        .cache_dir
+        .join(site::YEAR.to_string())
+        .join(format!("day_{day}.txt"))
+}
+
+/// Gets the input for `day` from the cache, or from the site if it isn't cached
+/// yet or `refresh` is set. Also returns whether it came from the cache.
+pub fn fetch_input(config: &Config, day: u8, refresh: bool) -> Result<(String, bool), SiteError> {
+    site::check_unlocked(day, SystemTime::now())?;
+
+    let cache_path = cache_path(config, day);
+
+    if !refresh {
+        if let Ok(input) = fs::read_to_string(&cache_path) {
+            return Ok((input, true));
+        }
+    }
+
+    let input = Site::new(config)?.input(day)?;
+
+    if let Some(parent) = cache_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&cache_path, &input)?;
+
+    Ok((input, false))
+}
+
+pub fn fetch(day: u8, refresh: bool, output: Option<PathBuf>) -> ExitCode {
+    let config = match Config::load() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("Failed to load the config: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let (input, cached) = match fetch_input(&config, day, refresh) {
+        Ok(fetched) => fetched,
+        Err(e) => {
+            eprintln!("Failed to fetch the input for day {day}: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let output = output.unwrap_or_else(|| default_output(day));
+
+    if let Err(e) = fs::write(&output, input) {
+        eprintln!("Failed to write the input to {}: {e}", output.display());
+        return ExitCode::FAILURE;
+    }
+
+    match cached {
+        true => println!(
+            "Wrote the cached input for day {day} to {}",
+            output.display()
+        ),
+        false => println!("Downloaded the input for day {day} to {}", output.display()),
+    }
+
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::site::test::{mock_site, test_config};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_fetch_is_cached() {
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = requests.clone();
+        let base_url = mock_site(move |_, _, _, _| {
+            counter.fetch_add(1, Ordering::Relaxed);
+            (200, "Time: 7 15 30\n".to_string())
+        });
+        let config = test_config(base_url);
+
+        assert_eq!(
+            fetch_input(&config, 6, false).unwrap(),
+            ("Time: 7 15 30\n".to_string(), false)
+        );
+        assert_eq!(
+            fetch_input(&config, 6, false).unwrap(),
+            ("Time: 7 15 30\n".to_string(), true)
+        );
+        assert_eq!(requests.load(Ordering::Relaxed), 1);
+
+        fetch_input(&config, 6, true).unwrap();
+        assert_eq!(requests.load(Ordering::Relaxed), 2);
+    }
+}
@@ -0,0 +1,72 @@
+//! Calibration lines made of digits and spelled out digits, separated by
+//! letters that can't be part of any digit's name.
+
+use aoc_common::Part;
+use rand::Rng;
+use rand_pcg::Pcg64;
+use std::ops::Range;
+
+use super::Generated;
+
+const WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// None of these letters appear in the name of a digit, so filler made of them
+/// can never join up with a word next to it.
+const FILLER: &[u8] = b"abcdjklmpqyz";
+
+fn push_filler(rng: &mut Pcg64, line: &mut String, lengths: Range<usize>) {
+    for _ in 0..rng.random_range(lengths) {
+        line.push(FILLER[rng.random_range(0..FILLER.len())] as char);
+    }
+}
+
+pub fn generate(rng: &mut Pcg64, lines: usize) -> Generated {
+    let mut input = String::new();
+    let mut part_one = 0u32;
+    let mut part_two = 0u32;
+
+    for _ in 0..lines {
+        let token_count = rng.random_range(1..=6);
+        // Every line needs at least one digit written as a digit for part one.
+        let forced_digit = rng.random_range(0..token_count);
+
+        let mut line = String::new();
+        let mut digits = Vec::new();
+        let mut all_digits = Vec::new();
+
+        push_filler(rng, &mut line, 0..4);
+
+        for i in 0..token_count {
+            if i > 0 {
+                push_filler(rng, &mut line, 1..4);
+            }
+
+            let value = rng.random_range(1..=9u32);
+
+            match i == forced_digit || rng.random_bool(0.5) {
+                true => {
+                    line.push(char::from_digit(value, 10).unwrap());
+                    digits.push(value);
+                }
+                false => line.push_str(WORDS[value as usize - 1]),
+            }
+
+            all_digits.push(value);
+        }
+
+        push_filler(rng, &mut line, 0..4);
+
+        part_one += digits[0] * 10 + digits[digits.len() - 1];
+        part_two += all_digits[0] * 10 + all_digits[all_digits.len() - 1];
+
+        input.push_str(&line);
+        input.push('\n');
+    }
+
+    Generated {
+        input,
+        answers: vec![(Part::One, part_one.into()), (Part::Two, part_two.into())],
+    }
+}
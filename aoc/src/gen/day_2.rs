@@ -0,0 +1,65 @@
+//! Games of random sets of cubes.
+
+use aoc_common::Part;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use rand_pcg::Pcg64;
+
+use super::Generated;
+
+const COLORS: [&str; 3] = ["red", "green", "blue"];
+
+/// The bag part one checks every game against.
+const BAG: [u32; 3] = [12, 13, 14];
+
+pub fn generate(rng: &mut Pcg64, games: usize) -> Generated {
+    let mut input = String::new();
+    let mut part_one = 0u64;
+    let mut part_two = 0u64;
+
+    for id in 1..=games {
+        let mut highest = [0u32; 3];
+        let mut sets = Vec::new();
+
+        for _ in 0..rng.random_range(1..=6) {
+            let mut cubes = Vec::new();
+
+            for (color, name) in COLORS.iter().enumerate() {
+                if rng.random_bool(0.7) {
+                    let amount = rng.random_range(1..=20);
+
+                    highest[color] = highest[color].max(amount);
+                    cubes.push(format!("{amount} {name}"));
+                }
+            }
+
+            // A set always has at least one color in it.
+            if cubes.is_empty() {
+                let amount = rng.random_range(1..=20);
+
+                highest[0] = highest[0].max(amount);
+                cubes.push(format!("{amount} red"));
+            }
+
+            cubes.shuffle(rng);
+            sets.push(cubes.join(", "));
+        }
+
+        if highest
+            .iter()
+            .zip(BAG)
+            .all(|(&amount, limit)| amount <= limit)
+        {
+            part_one += id as u64;
+        }
+
+        part_two += highest.iter().map(|&amount| amount as u64).product::<u64>();
+
+        input.push_str(&format!("Game {id}: {}\n", sets.join("; ")));
+    }
+
+    Generated {
+        input,
+        answers: vec![(Part::One, part_one.into()), (Part::Two, part_two.into())],
+    }
+}
@@ -0,0 +1,126 @@
+//! Engine schematics laid out so that we know which symbols touch which
+//! numbers. The schematic is split into bands of three rows, with numbers only
+//! in the middle row of each band, so numbers in different bands never share a
+//! symbol. Within a band, the numbers come in small groups separated by dots,
+//! and each group is one of a few shapes.
+
+use aoc_common::Part;
+use rand::Rng;
+use rand_pcg::Pcg64;
+
+use super::Generated;
+
+/// Symbols other than `*`, which would make a gear when next to two numbers.
+const SYMBOLS: &[u8] = b"#$%&+-/=@";
+
+/// A group of numbers and symbols in the middle row of a band.
+enum Shape {
+    /// A number with nothing next to it.
+    Lone(u32),
+    /// A number with a symbol right after it.
+    SymbolAfter(u32),
+    /// A number with a symbol above or below one of its digits.
+    SymbolOver(u32),
+    /// Two numbers with a `*` between them.
+    Gear(u32, u32),
+    /// Two numbers with a dot between them, and a `*` above or below the dot.
+    DiagonalGear(u32, u32),
+}
+
+impl Shape {
+    fn random(rng: &mut Pcg64) -> Self {
+        let kind = rng.random_range(0..5);
+        let mut number = || rng.random_range(1..=999);
+
+        match kind {
+            0 => Self::Lone(number()),
+            1 => Self::SymbolAfter(number()),
+            2 => Self::SymbolOver(number()),
+            3 => Self::Gear(number(), number()),
+            _ => Self::DiagonalGear(number(), number()),
+        }
+    }
+
+    fn width(&self) -> usize {
+        let digits = |number: &u32| number.to_string().len();
+
+        match self {
+            Self::Lone(number) | Self::SymbolOver(number) => digits(number),
+            Self::SymbolAfter(number) => digits(number) + 1,
+            Self::Gear(left, right) | Self::DiagonalGear(left, right) => {
+                digits(left) + 1 + digits(right)
+            }
+        }
+    }
+}
+
+pub fn generate(rng: &mut Pcg64, size: usize) -> Generated {
+    let mut rows = vec![vec![b'.'; size]; size];
+    let mut part_one = 0usize;
+    let mut part_two = 0usize;
+
+    for band in 0..size / 3 {
+        let middle = band * 3 + 1;
+        let mut x = 0;
+
+        loop {
+            let shape = Shape::random(rng);
+
+            if x + shape.width() > size {
+                break;
+            }
+
+            let write = |rows: &mut Vec<Vec<u8>>, x: usize, text: &str| {
+                rows[middle][x..x + text.len()].copy_from_slice(text.as_bytes());
+            };
+            let symbol = SYMBOLS[rng.random_range(0..SYMBOLS.len())];
+            let over = match rng.random_bool(0.5) {
+                true => middle - 1,
+                false => middle + 1,
+            };
+
+            match shape {
+                Shape::Lone(number) => write(&mut rows, x, &number.to_string()),
+                Shape::SymbolAfter(number) => {
+                    write(&mut rows, x, &format!("{number}{}", symbol as char));
+                    part_one += number as usize;
+                }
+                Shape::SymbolOver(number) => {
+                    let digits = number.to_string();
+
+                    write(&mut rows, x, &digits);
+                    rows[over][x + rng.random_range(0..digits.len())] = symbol;
+                    part_one += number as usize;
+                }
+                Shape::Gear(left, right) => {
+                    write(&mut rows, x, &format!("{left}*{right}"));
+                    part_one += (left + right) as usize;
+                    part_two += (left * right) as usize;
+                }
+                Shape::DiagonalGear(left, right) => {
+                    let left_digits = left.to_string();
+
+                    write(&mut rows, x, &format!("{left_digits}.{right}"));
+                    rows[over][x + left_digits.len()] = b'*';
+                    part_one += (left + right) as usize;
+                    part_two += (left * right) as usize;
+                }
+            }
+
+            // A dot after every shape keeps it away from the next one.
+            x += shape.width() + 1;
+        }
+    }
+
+    let mut input = String::with_capacity(size * (size + 1));
+
+    for row in rows {
+        input.push_str(std::str::from_utf8(&row).unwrap());
+        input.push('\n');
+    }
+
+    Generated {
+        input,
+        answers: vec![(Part::One, part_one.into()), (Part::Two, part_two.into())],
+    }
+}
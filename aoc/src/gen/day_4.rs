@@ -0,0 +1,73 @@
+//! Scratchcards with a chosen number of matches each. Most cards win nothing,
+//! so that the number of copies in part two doesn't grow without bound.
+
+use aoc_common::Part;
+use rand::seq::{IteratorRandom, SliceRandom};
+use rand::Rng;
+use rand_pcg::Pcg64;
+
+use super::Generated;
+
+const WINNING_NUMBERS: usize = 10;
+const NUMBERS_YOU_HAVE: usize = 25;
+
+pub fn generate(rng: &mut Pcg64, cards: usize) -> Generated {
+    let mut input = String::new();
+    let mut matches = Vec::with_capacity(cards);
+
+    for id in 1..=cards {
+        // A card can't win copies of cards past the end of the table.
+        let most_matches = (cards - id).min(WINNING_NUMBERS);
+        let card_matches = match rng.random_bool(0.6) {
+            true => 0,
+            false => rng.random_range(0..=most_matches.min(3)),
+        };
+
+        let numbers = (1..=99).choose_multiple(rng, WINNING_NUMBERS + NUMBERS_YOU_HAVE);
+        let (winning, others) = numbers.split_at(WINNING_NUMBERS);
+
+        let mut have = winning[..card_matches].to_vec();
+        have.extend(&others[..NUMBERS_YOU_HAVE - card_matches]);
+        have.shuffle(rng);
+
+        let mut winning = winning.to_vec();
+        winning.shuffle(rng);
+
+        let format_numbers = |numbers: &[u32]| {
+            numbers
+                .iter()
+                .map(|number| format!("{number:>2}"))
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+
+        input.push_str(&format!(
+            "Card {id:>3}: {} | {}\n",
+            format_numbers(&winning),
+            format_numbers(&have)
+        ));
+
+        matches.push(card_matches);
+    }
+
+    let part_one = matches
+        .iter()
+        .filter(|&&card_matches| card_matches > 0)
+        .map(|&card_matches| 1u64 << (card_matches - 1))
+        .sum::<u64>();
+
+    let mut copies = vec![1u64; cards];
+
+    for (i, &card_matches) in matches.iter().enumerate() {
+        for won in i + 1..=i + card_matches {
+            copies[won] += copies[i];
+        }
+    }
+
+    let part_two = copies.iter().sum::<u64>();
+
+    Generated {
+        input,
+        answers: vec![(Part::One, part_one.into()), (Part::Two, part_two.into())],
+    }
+}
@@ -0,0 +1,166 @@
+//! Almanacs with seed ranges as large as we like. The answers are worked out
+//! by mapping whole ranges at a time, so they stay quick to find even when the
+//! seed ranges cover billions of seeds.
+
+use aoc_common::Part;
+use rand::Rng;
+use rand_pcg::Pcg64;
+use std::ops::Range;
+
+use super::Generated;
+
+const MAP_NAMES: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+
+const SEED_RANGES: i64 = 10;
+
+/// Every value lives below this, like in the real inputs.
+const LIMIT: i64 = 1 << 32;
+
+/// One line of a map, moving `source` to start at `destination`.
+struct MapLine {
+    destination: i64,
+    source: Range<i64>,
+}
+
+/// Makes the lines of one map. Their sources never overlap, like in the real
+/// inputs, and the gaps between them map to themselves.
+fn random_map(rng: &mut Pcg64) -> Vec<MapLine> {
+    let line_count = rng.random_range(10..=40);
+    let mut boundaries = (0..line_count * 2)
+        .map(|_| rng.random_range(0..LIMIT))
+        .collect::<Vec<_>>();
+
+    boundaries.sort_unstable();
+    boundaries.dedup();
+
+    boundaries
+        .chunks_exact(2)
+        .map(|pair| {
+            let source = pair[0]..pair[1];
+
+            MapLine {
+                destination: rng.random_range(0..LIMIT - (source.end - source.start)),
+                source,
+            }
+        })
+        .collect()
+}
+
+/// Maps every range through `map`, splitting them where they cross the edge of
+/// a line.
+fn map_ranges(ranges: Vec<Range<i64>>, map: &[MapLine]) -> Vec<Range<i64>> {
+    let mut mapped = Vec::new();
+
+    for range in ranges {
+        let mut unmapped = vec![range];
+
+        for line in map {
+            let offset = line.destination - line.source.start;
+            let mut remaining = Vec::new();
+
+            for range in unmapped {
+                let start = range.start.max(line.source.start);
+                let end = range.end.min(line.source.end);
+
+                if start >= end {
+                    remaining.push(range);
+                    continue;
+                }
+
+                mapped.push(start + offset..end + offset);
+
+                if range.start < start {
+                    remaining.push(range.start..start);
+                }
+
+                if end < range.end {
+                    remaining.push(end..range.end);
+                }
+            }
+
+            unmapped = remaining;
+        }
+
+        mapped.extend(unmapped);
+    }
+
+    mapped
+}
+
+fn lowest_location(ranges: Vec<Range<i64>>, maps: &[Vec<MapLine>]) -> i64 {
+    maps.iter()
+        .fold(ranges, |ranges, map| map_ranges(ranges, map))
+        .iter()
+        .map(|range| range.start)
+        .min()
+        .unwrap()
+}
+
+pub fn generate(rng: &mut Pcg64, seed_count: usize) -> Generated {
+    // We split the seeds up into ranges of random lengths that add up to
+    // `seed_count`.
+    let seed_count = seed_count as i64;
+    let mut cuts = (1..SEED_RANGES)
+        .map(|_| rng.random_range(0..=seed_count))
+        .collect::<Vec<_>>();
+    cuts.push(0);
+    cuts.push(seed_count);
+    cuts.sort_unstable();
+
+    let seed_ranges = cuts
+        .windows(2)
+        .map(|cut| cut[1] - cut[0])
+        .filter(|&length| length > 0)
+        .map(|length| {
+            let start = rng.random_range(0..LIMIT.max(length) * 2 - length);
+
+            start..start + length
+        })
+        .collect::<Vec<_>>();
+
+    let maps = MAP_NAMES.map(|_| random_map(rng));
+
+    let mut input = String::from("seeds:");
+
+    for range in &seed_ranges {
+        input.push_str(&format!(" {} {}", range.start, range.end - range.start));
+    }
+
+    input.push('\n');
+
+    for (name, map) in MAP_NAMES.iter().zip(&maps) {
+        input.push_str(&format!("\n{name} map:\n"));
+
+        for line in map {
+            input.push_str(&format!(
+                "{} {} {}\n",
+                line.destination,
+                line.source.start,
+                line.source.end - line.source.start
+            ));
+        }
+    }
+
+    // In part one the numbers on the seeds line are all single seeds.
+    let single_seeds = seed_ranges
+        .iter()
+        .flat_map(|range| [range.start, range.end - range.start])
+        .map(|seed| seed..seed + 1)
+        .collect();
+
+    let part_one = lowest_location(single_seeds, &maps);
+    let part_two = lowest_location(seed_ranges, &maps);
+
+    Generated {
+        input,
+        answers: vec![(Part::One, part_one.into()), (Part::Two, part_two.into())],
+    }
+}
@@ -0,0 +1,82 @@
+//! Race sheets. Both parts count the same thing, so the counts are worked out
+//! directly instead of trying every button press.
+
+use aoc_common::Part;
+use rand::Rng;
+use rand_pcg::Pcg64;
+
+use super::Generated;
+
+/// Counts how many ways there are to beat `distance` in a race of `time`.
+/// Holding the button for `p` goes `p * (time - p)`, which is highest in the
+/// middle, so we look for the first winning press and mirror it.
+fn ways_to_win(time: i128, distance: i128) -> u64 {
+    let goes_far_enough = |press: i128| press * (time - press) > distance;
+
+    if !goes_far_enough(time / 2) {
+        return 0;
+    }
+
+    let (mut low, mut high) = (0, time / 2);
+
+    while low < high {
+        let middle = (low + high) / 2;
+
+        match goes_far_enough(middle) {
+            true => high = middle,
+            false => low = middle + 1,
+        }
+    }
+
+    (time - 2 * low + 1) as u64
+}
+
+pub fn generate(rng: &mut Pcg64, races: usize) -> Generated {
+    let mut times = Vec::new();
+    let mut distances = Vec::new();
+
+    for _ in 0..races {
+        let time = rng.random_range(7..=99i64);
+        // The record is always beatable, but only just for some races.
+        let best = (time / 2) * (time - time / 2);
+        let distance = rng.random_range(best / 3..best);
+
+        times.push(time);
+        distances.push(distance);
+    }
+
+    let part_one = times
+        .iter()
+        .zip(&distances)
+        .map(|(&time, &distance)| ways_to_win(time as i128, distance as i128))
+        .product::<u64>();
+
+    let join = |numbers: &[i64]| {
+        numbers
+            .iter()
+            .map(|number| number.to_string())
+            .collect::<String>()
+            .parse::<i128>()
+            .unwrap()
+    };
+
+    let part_two = ways_to_win(join(&times), join(&distances));
+
+    let columns = |numbers: &[i64]| {
+        numbers
+            .iter()
+            .map(|number| format!("{number:>5}"))
+            .collect::<String>()
+    };
+
+    let input = format!(
+        "Time:    {}\nDistance:{}\n",
+        columns(&times),
+        columns(&distances)
+    );
+
+    Generated {
+        input,
+        answers: vec![(Part::One, part_one.into()), (Part::Two, part_two.into())],
+    }
+}
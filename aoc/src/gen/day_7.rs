@@ -0,0 +1,94 @@
+//! Hands of Camel Cards. There are only so many different hands, so large
+//! inputs repeat some of them, and a repeated hand always gets the same bid so
+//! that it doesn't matter which copy is ranked first.
+
+use aoc_common::Part;
+use rand::Rng;
+use rand_pcg::Pcg64;
+use std::collections::HashMap;
+
+use super::Generated;
+
+/// The cards from weakest to strongest in part one.
+const CARDS: &[u8; 13] = b"23456789TJQKA";
+
+/// The cards from weakest to strongest in part two, where `J` is a joker.
+const JOKER_CARDS: &[u8; 13] = b"J23456789TQKA";
+
+/// A key that sorts hands from weakest to strongest: the hand's type (from
+/// the sorted counts of each card), then the strength of each card in order.
+fn strength(hand: &[u8; 5], jokers: bool) -> (Vec<u8>, [usize; 5]) {
+    let order = match jokers {
+        true => JOKER_CARDS,
+        false => CARDS,
+    };
+
+    let mut counts = HashMap::new();
+    let mut joker_count = 0;
+
+    for &card in hand {
+        match jokers && card == b'J' {
+            true => joker_count += 1,
+            false => *counts.entry(card).or_insert(0u8) += 1,
+        }
+    }
+
+    let mut counts = counts.into_values().collect::<Vec<_>>();
+    counts.sort_unstable_by(|a, b| b.cmp(a));
+
+    // Jokers always do best joining whichever card there's most of.
+    match counts.first_mut() {
+        Some(most) => *most += joker_count,
+        None => counts.push(joker_count),
+    }
+
+    let cards = hand.map(|card| order.iter().position(|&c| c == card).unwrap());
+
+    (counts, cards)
+}
+
+fn total_winnings(hands: &[([u8; 5], u64)], jokers: bool) -> u64 {
+    let mut ranked = hands
+        .iter()
+        .map(|(hand, bid)| (strength(hand, jokers), *bid))
+        .collect::<Vec<_>>();
+
+    ranked.sort_unstable();
+
+    ranked
+        .iter()
+        .enumerate()
+        .map(|(i, (_, bid))| bid * (i as u64 + 1))
+        .sum()
+}
+
+pub fn generate(rng: &mut Pcg64, hand_count: usize) -> Generated {
+    let mut bids = HashMap::new();
+    let mut hands = Vec::with_capacity(hand_count);
+
+    for _ in 0..hand_count {
+        // Picking from fewer kinds of card every so often gives us more pairs
+        // and full houses than picking uniformly would.
+        let kinds = rng.random_range(2..=13);
+        let hand = [(); 5].map(|_| CARDS[rng.random_range(0..kinds)]);
+        let bid = *bids
+            .entry(hand)
+            .or_insert_with(|| rng.random_range(1..=1000u64));
+
+        hands.push((hand, bid));
+    }
+
+    let mut input = String::new();
+
+    for (hand, bid) in &hands {
+        input.push_str(&format!("{} {bid}\n", std::str::from_utf8(hand).unwrap()));
+    }
+
+    let part_one = total_winnings(&hands, false);
+    let part_two = total_winnings(&hands, true);
+
+    Generated {
+        input,
+        answers: vec![(Part::One, part_one.into()), (Part::Two, part_two.into())],
+    }
+}
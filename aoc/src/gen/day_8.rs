@@ -0,0 +1,131 @@
+//! Networks built like the real ones: every ghost starts on a node ending in
+//! `A`, follows a loop through its own nodes and lands on a node ending in `Z`
+//! after a whole number of passes through the directions, then carries on round
+//! the same loop. The first ghost starts on `AAA` and lands on `ZZZ`.
+//!
+//! Each loop's length is the number of directions times a prime, and the
+//! number of directions is a prime too, so the ghosts first line up after the
+//! product of all those primes.
+
+use aoc_common::Part;
+use rand::seq::{IteratorRandom, SliceRandom};
+use rand::Rng;
+use rand_pcg::Pcg64;
+
+use super::Generated;
+
+const GHOSTS: usize = 6;
+
+/// Node names are three letters or digits long. Nodes that aren't a ghost's
+/// start or end can't end in `A` or `Z`, which leaves this many names.
+pub const MAX_NODES: usize = 62 * 62 * 60;
+
+const NAME_CHARACTERS: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// The `i`th name that doesn't end in `A` or `Z`.
+fn inner_name(i: usize) -> String {
+    let last_characters = NAME_CHARACTERS
+        .iter()
+        .filter(|&&c| c != b'A' && c != b'Z')
+        .collect::<Vec<_>>();
+
+    let last = last_characters[i % last_characters.len()];
+    let rest = i / last_characters.len();
+
+    String::from_utf8(vec![
+        NAME_CHARACTERS[rest / NAME_CHARACTERS.len()],
+        NAME_CHARACTERS[rest % NAME_CHARACTERS.len()],
+        *last,
+    ])
+    .unwrap()
+}
+
+fn is_prime(number: usize) -> bool {
+    number >= 2
+        && (2..)
+            .take_while(|d| d * d <= number)
+            .all(|d| !number.is_multiple_of(d))
+}
+
+pub fn generate(rng: &mut Pcg64, nodes: usize) -> Generated {
+    // Every ghost gets about the same share of the nodes, which is split
+    // between the length of the directions and the number of passes.
+    let loop_length = (nodes / GHOSTS).max(4);
+    let root = (loop_length as f64).sqrt() as usize;
+
+    let direction_count = (root / 2..=root.max(2))
+        .filter(|&n| is_prime(n))
+        .choose(rng)
+        .unwrap_or(2);
+    let passes_around = (loop_length / direction_count).max(2);
+
+    let mut primes = (passes_around / 2..)
+        .filter(|&n| is_prime(n) && n != direction_count)
+        .take(GHOSTS * 2)
+        .collect::<Vec<_>>();
+    primes.shuffle(rng);
+    let passes = &primes[..GHOSTS];
+
+    let directions = (0..direction_count)
+        .map(|_| match rng.random_bool(0.5) {
+            true => 'L',
+            false => 'R',
+        })
+        .collect::<Vec<_>>();
+
+    let mut names = 0..;
+    let mut lines = Vec::new();
+    let mut loop_lengths = Vec::new();
+
+    for (ghost, &passes) in passes.iter().enumerate() {
+        let length = direction_count * passes;
+        let (start, end) = match ghost {
+            0 => ("AAA".to_string(), "ZZZ".to_string()),
+            _ => {
+                let c = NAME_CHARACTERS[ghost] as char;
+
+                (format!("{c}{c}A"), format!("{c}{c}Z"))
+            }
+        };
+
+        // The loop goes start, then `length - 1` inner nodes, then end. The
+        // end goes back to the first inner node, just like the start does.
+        let mut path = vec![start];
+        path.extend((1..length).map(|_| inner_name(names.next().unwrap())));
+        path.push(end);
+
+        for (step, node) in path.iter().enumerate() {
+            let next = match step == length {
+                true => &path[1],
+                false => &path[step + 1],
+            };
+            // The side we never take can go anywhere on the loop.
+            let other = &path[rng.random_range(0..path.len())];
+
+            let (left, right) = match directions[step % direction_count] {
+                'L' => (next, other),
+                _ => (other, next),
+            };
+
+            lines.push(format!("{node} = ({left}, {right})"));
+        }
+
+        loop_lengths.push(length as u64);
+    }
+
+    lines.shuffle(rng);
+
+    let input = format!(
+        "{}\n\n{}\n",
+        directions.iter().collect::<String>(),
+        lines.join("\n")
+    );
+
+    let part_one = loop_lengths[0];
+    let part_two = direction_count as u64 * passes.iter().map(|&p| p as u64).product::<u64>();
+
+    Generated {
+        input,
+        answers: vec![(Part::One, part_one.into()), (Part::Two, part_two.into())],
+    }
+}
@@ -0,0 +1,51 @@
+//! Histories that are the values of a polynomial with integer coefficients at
+//! 0 to 20. Taking differences brings a polynomial down a degree each time, so
+//! the puzzle's extrapolation gives exactly the polynomial's value at 21 and
+//! at -1.
+
+use aoc_common::Part;
+use rand::Rng;
+use rand_pcg::Pcg64;
+
+use super::Generated;
+
+const VALUES_PER_LINE: i64 = 21;
+
+fn evaluate(coefficients: &[i64], x: i64) -> i64 {
+    coefficients
+        .iter()
+        .rev()
+        .fold(0, |value, coefficient| value * x + coefficient)
+}
+
+pub fn generate(rng: &mut Pcg64, line_count: usize) -> Generated {
+    let mut input = String::new();
+    let mut part_one = 0;
+    let mut part_two = 0;
+
+    for _ in 0..line_count {
+        // Small coefficients keep the values about as big as the real ones.
+        let degree = rng.random_range(0..=6);
+        let coefficients = (0..=degree)
+            .map(|power| {
+                let bound = 20 / (power + 1);
+                rng.random_range(-bound..=bound)
+            })
+            .collect::<Vec<i64>>();
+
+        let history = (0..VALUES_PER_LINE)
+            .map(|x| evaluate(&coefficients, x).to_string())
+            .collect::<Vec<_>>();
+
+        input.push_str(&history.join(" "));
+        input.push('\n');
+
+        part_one += evaluate(&coefficients, VALUES_PER_LINE);
+        part_two += evaluate(&coefficients, -1);
+    }
+
+    Generated {
+        input,
+        answers: vec![(Part::One, part_one.into()), (Part::Two, part_two.into())],
+    }
+}
@@ -0,0 +1,237 @@
+//! `aoc gen`, which writes synthetic puzzle inputs of any size for stress
+//! testing the solvers. Every generator builds its input so that the answers
+//! are known without running a solver, and they're written next to the input.
+
+mod day_1;
+mod day_2;
+mod day_3;
+mod day_4;
+mod day_5;
+mod day_6;
+mod day_7;
+mod day_8;
+mod day_9;
+
+use crate::answers::{self, KnownAnswer};
+use aoc_common::{Answer, Part};
+use rand::SeedableRng;
+use rand_pcg::Pcg64;
+use std::fs;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+/// A generated input along with the answers to the parts we know.
+pub struct Generated {
+    pub input: String,
+    pub answers: Vec<(Part, Answer)>,
+}
+
+struct Generator {
+    day: u8,
+    /// What `--size` means for this day.
+    size_meaning: &'static str,
+    /// About the size of the real inputs.
+    default_size: usize,
+    max_size: usize,
+    generate: fn(&mut Pcg64, usize) -> Generated,
+}
+
+static GENERATORS: &[Generator] = &[
+    Generator {
+        day: 1,
+        size_meaning: "the number of lines",
+        default_size: 1000,
+        max_size: usize::MAX,
+        generate: day_1::generate,
+    },
+    Generator {
+        day: 2,
+        size_meaning: "the number of games",
+        default_size: 100,
+        max_size: usize::MAX,
+        generate: day_2::generate,
+    },
+    Generator {
+        day: 3,
+        size_meaning: "the width and height of the schematic",
+        default_size: 140,
+        max_size: 50_000,
+        generate: day_3::generate,
+    },
+    Generator {
+        day: 4,
+        size_meaning: "the number of cards",
+        default_size: 200,
+        max_size: usize::MAX,
+        generate: day_4::generate,
+    },
+    Generator {
+        day: 5,
+        size_meaning: "how many seeds the seed ranges cover in total",
+        default_size: 2_000_000_000,
+        max_size: 1 << 40,
+        generate: day_5::generate,
+    },
+    Generator {
+        day: 6,
+        // The large race joins every race together, so more than four of them
+        // would make its distance too big for 64 bits.
+        size_meaning: "the number of races",
+        default_size: 4,
+        max_size: 4,
+        generate: day_6::generate,
+    },
+    Generator {
+        day: 7,
+        size_meaning: "the number of hands",
+        default_size: 1000,
+        max_size: usize::MAX,
+        generate: day_7::generate,
+    },
+    Generator {
+        day: 8,
+        size_meaning: "the number of nodes in the network",
+        default_size: 750,
+        max_size: day_8::MAX_NODES,
+        generate: day_8::generate,
+    },
+    Generator {
+        day: 9,
+        size_meaning: "the number of lines",
+        default_size: 200,
+        max_size: usize::MAX,
+        generate: day_9::generate,
+    },
+];
+
+fn find(day: u8) -> Option<&'static Generator> {
+    GENERATORS.iter().find(|generator| generator.day == day)
+}
+
+/// Generates the input for `day` from `seed`. The same seed and size always
+/// give the same input.
+pub fn generate(day: u8, seed: u64, size: Option<usize>) -> Result<Generated, String> {
+    let generator = find(day).ok_or_else(|| format!("There is no generator for day {day}."))?;
+    let size = size.unwrap_or(generator.default_size);
+
+    if !(1..=generator.max_size).contains(&size) {
+        return Err(format!(
+            "The size for day {day} is {}, which has to be between 1 and {}.",
+            generator.size_meaning, generator.max_size
+        ));
+    }
+
+    let mut rng = Pcg64::seed_from_u64(seed);
+
+    Ok((generator.generate)(&mut rng, size))
+}
+
+pub fn gen(day: u8, seed: u64, size: Option<usize>, output: Option<PathBuf>) -> ExitCode {
+    let generated = match generate(day, seed, size) {
+        Ok(generated) => generated,
+        Err(message) => {
+            eprintln!("{message}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let output = output.unwrap_or_else(|| {
+        let size = size.map_or("default".to_string(), |size| size.to_string());
+
+        crate::workspace_root()
+            .join("target")
+            .join("gen")
+            .join(format!("day_{day}_size_{size}_seed_{seed}.txt"))
+    });
+    let answers_path = output.with_extension("answers.json");
+
+    let input_sha256 = answers::hash_input(&generated.input);
+    let known_answers = generated
+        .answers
+        .iter()
+        .map(|(part, answer)| KnownAnswer {
+            day,
+            part: part.number(),
+            input_sha256: input_sha256.clone(),
+            answer: answer.to_string(),
+        })
+        .collect::<Vec<_>>();
+
+    let written = output
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| fs::write(&output, &generated.input))
+        .and_then(|_| {
+            let json = serde_json::to_string_pretty(&known_answers)
+                .expect("Known answers should always serialize.");
+
+            fs::write(&answers_path, json + "\n")
+        });
+
+    if let Err(e) = written {
+        eprintln!(
+            "Failed to write the generated input to {}: {e}",
+            output.display()
+        );
+        return ExitCode::FAILURE;
+    }
+
+    println!("Wrote the input to {}", output.display());
+
+    for (part, answer) in &generated.answers {
+        println!("Day {day} Part {part}: {answer}");
+    }
+
+    println!("Wrote the answers to {}", answers_path.display());
+
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::registry;
+    use aoc_common::Progress;
+
+    #[test]
+    fn test_generators_match_solvers() {
+        for generator in GENERATORS {
+            // Day 5 part 2 tries every seed one by one, so its seed ranges
+            // have to stay small.
+            let size = match generator.day {
+                5 => 100_000,
+                _ => generator.default_size.min(500),
+            };
+            let generated = generate(generator.day, 2023, Some(size)).unwrap();
+
+            for (part, answer) in &generated.answers {
+                // Day 8 part 2 multiplies the cycle lengths instead of taking
+                // their least common multiple, and day 9 part 1 never finishes.
+                if matches!((generator.day, part), (8, Part::Two) | (9, Part::One)) {
+                    continue;
+                }
+
+                let Some(solver) = registry::find(generator.day, *part) else {
+                    continue;
+                };
+
+                assert_eq!(
+                    &solver.run(&generated.input, &Progress::new()).unwrap().0,
+                    answer,
+                    "day {} part {part}",
+                    generator.day
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_same_seed_same_input() {
+        let first = generate(7, 1, Some(50)).unwrap();
+        let second = generate(7, 1, Some(50)).unwrap();
+        let other = generate(7, 2, Some(50)).unwrap();
+
+        assert_eq!(first.input, second.input);
+        assert_ne!(first.input, other.input);
+    }
+}
@@ -0,0 +1,366 @@
+//! A single runner for every day in the workspace.
+
+mod answers;
+mod bench;
+mod child;
+mod compare;
+mod config;
+mod fetch;
+mod gen;
+mod output;
+mod registry;
+mod run;
+mod run_all;
+mod scaffold;
+mod serve;
+mod site;
+mod step;
+mod submit;
+mod verify;
+mod watch;
+
+use clap::{ArgAction, Parser, Subcommand};
+use output::Format;
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Duration;
+use tracing_subscriber::filter::{EnvFilter, LevelFilter};
+
+/// Counts what every run allocates, so that `aoc bench` and `--format json`
+/// can report it next to the times.
+#[cfg(feature = "count-allocations")]
+#[global_allocator]
+static ALLOCATOR: aoc_common::memory::CountingAllocator = aoc_common::memory::CountingAllocator;
+
+#[derive(Parser)]
+#[command(about = "Runs the Advent of Code 2023 solutions in this workspace.")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Show what the solvers are doing on stderr, `-v` for each map, race or
+    /// cycle and `-vv` for every step. `RUST_LOG` picks out more, like
+    /// `RUST_LOG=day_5=trace`.
+    #[arg(short, long, global = true, action = ArgAction::Count)]
+    verbose: u8,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Runs the solver for a day, printing the answer to each part.
+    Run {
+        #[arg(required_unless_present = "all")]
+        day: Option<u8>,
+        /// Only run this part of the day.
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Read the puzzle input from this file instead of the embedded
+        /// `input.txt`. Use `-` to read from stdin.
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+        /// How to print the answers.
+        #[arg(short, long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        /// Run every day and part at once, each in its own process, and check
+        /// the answers against the known ones.
+        #[arg(long, conflicts_with_all = ["day", "part", "input"])]
+        all: bool,
+        /// Stop a part that's still running after this many seconds. With
+        /// `--all` a part is stopped after 30 seconds unless this says
+        /// otherwise.
+        #[arg(short, long)]
+        timeout: Option<u64>,
+        /// With `--all`, the most memory each part can use, in MiB. Only
+        /// enforced on Unix.
+        #[arg(long, default_value_t = 2048)]
+        memory_limit: u64,
+        /// With `--all`, how many parts to run at once.
+        #[arg(short, long, default_value_t = run_all::default_jobs())]
+        jobs: usize,
+    },
+    /// Lists every day and part that has a solver.
+    List,
+    /// Checks every solver against the known answers for its embedded input.
+    Verify {
+        /// Only check this day.
+        #[arg(short, long)]
+        day: Option<u8>,
+        /// The file of known answers.
+        #[arg(long, default_value_os_t = answers::default_path())]
+        answers: PathBuf,
+    },
+    /// Runs the solver for a day and records its answers as known-good.
+    Accept {
+        day: u8,
+        /// Only accept this part of the day.
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Accept the answers for this input instead of the embedded one.
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+        /// The file of known answers.
+        #[arg(long, default_value_os_t = answers::default_path())]
+        answers: PathBuf,
+    },
+    /// Times every solver that has a known answer, or every part of one day,
+    /// and prints the median times.
+    Bench {
+        /// Only time this day, whether or not it has known answers.
+        #[arg(short, long)]
+        day: Option<u8>,
+        /// Only time this part of the day.
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// How many times to run each part.
+        #[arg(short, long, default_value_t = 10)]
+        samples: usize,
+        /// The file of known answers.
+        #[arg(long, default_value_os_t = answers::default_path())]
+        answers: PathBuf,
+    },
+    /// Downloads a day's puzzle input into its crate, using the cached copy
+    /// if there is one.
+    Fetch {
+        day: u8,
+        /// Download the input again even if it's cached.
+        #[arg(long)]
+        refresh: bool,
+        /// Write the input here instead of `day_N/input.txt`.
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Runs the solver for one part of a day and submits its answer, unless
+    /// the site has already turned that answer down.
+    Submit {
+        day: u8,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Solve this input instead of the embedded one.
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+    },
+    /// Creates the crate for a new day and registers it with the workspace,
+    /// the runner and the benchmarks.
+    New { day: u8 },
+    /// Writes a synthetic input for a day, along with its answers, for stress
+    /// testing the solver.
+    Gen {
+        day: u8,
+        /// The same seed and size always give the same input.
+        #[arg(short, long, default_value_t = 2023)]
+        seed: u64,
+        /// How big to make the input. What this counts depends on the day,
+        /// and it defaults to about the size of the real inputs.
+        #[arg(long)]
+        size: Option<usize>,
+        /// Write the input here instead of `target/gen`. The answers are
+        /// written next to it.
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Steps through a day that works one step at a time (4, 8 or 9) in the
+    /// terminal, showing its state as it goes.
+    Step {
+        day: u8,
+        /// Step through this input instead of the embedded one.
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+    },
+    /// Runs a day against every input in a directory and prints a table of
+    /// the answers, flagging inputs that fail or look wrong.
+    Compare {
+        day: u8,
+        /// The directory of inputs, one per file.
+        dir: PathBuf,
+        /// Only run this part of the day.
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// How to print the answers.
+        #[arg(short, long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        /// Kill a part that's still running after this many seconds.
+        #[arg(short, long, default_value_t = 30)]
+        timeout: u64,
+        /// The most memory each part can use, in MiB. Only enforced on Unix.
+        #[arg(long, default_value_t = 2048)]
+        memory_limit: u64,
+        /// How many parts to run at once.
+        #[arg(short, long, default_value_t = run_all::default_jobs())]
+        jobs: usize,
+    },
+    /// Starts a local HTTP server that solves the inputs posted to
+    /// `/day/{day}/part/{part}` and answers with JSON.
+    Serve {
+        #[arg(short, long, default_value_t = 2023)]
+        port: u16,
+        /// Give up on a part that's still running after this many seconds.
+        #[arg(short, long, default_value_t = 30)]
+        timeout: u64,
+        /// The most memory each part can use, in MiB. Only enforced on Unix.
+        #[arg(long, default_value_t = 2048)]
+        memory_limit: u64,
+        /// How many requests to solve at once. Any more wait their turn.
+        #[arg(short, long, default_value_t = run_all::default_jobs())]
+        jobs: usize,
+    },
+    /// Rebuilds and re-runs a day whenever its source or input changes,
+    /// showing the new answers next to the last run's.
+    Watch {
+        day: u8,
+        /// Only run this part of the day.
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Kill a part that's still running after this many seconds.
+        #[arg(short, long, default_value_t = 30)]
+        timeout: u64,
+        /// Build with optimisations, for days that are too slow without them.
+        #[arg(long)]
+        release: bool,
+    },
+}
+
+/// Prints the solvers' traces to stderr, so they don't get mixed up with the
+/// answers. `RUST_LOG` is read on top of the level from `-v`.
+fn init_tracing(verbose: u8) {
+    let level = match verbose {
+        0 => LevelFilter::WARN,
+        1 => LevelFilter::DEBUG,
+        _ => LevelFilter::TRACE,
+    };
+
+    let filter = EnvFilter::builder()
+        .with_default_directive(level.into())
+        .from_env_lossy();
+
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(std::io::stderr)
+        .with_ansi(std::io::stderr().is_terminal())
+        .init();
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    // The stepper draws over the whole terminal, so there's nowhere to put
+    // the traces.
+    if !matches!(cli.command, Command::Step { .. }) {
+        init_tracing(cli.verbose);
+    }
+
+    match cli.command {
+        Command::Run {
+            all: true,
+            format,
+            timeout,
+            memory_limit,
+            jobs,
+            ..
+        } => run_all::run_all(
+            format,
+            child::Limits {
+                timeout: Duration::from_secs(timeout.unwrap_or(30)),
+                memory: Some(memory_limit << 20),
+            },
+            jobs,
+        ),
+        Command::Run {
+            day,
+            part,
+            input,
+            format,
+            timeout,
+            ..
+        } => run::run(
+            day.expect("clap makes sure there's a day without `--all`"),
+            part,
+            input,
+            format,
+            timeout.map(Duration::from_secs),
+            // The bar would get in the way of the traces.
+            cli.verbose == 0,
+        ),
+        Command::List => {
+            list();
+            ExitCode::SUCCESS
+        }
+        Command::Verify { day, answers } => verify::verify(day, answers),
+        Command::Accept {
+            day,
+            part,
+            input,
+            answers,
+        } => verify::accept(day, part, input, answers),
+        Command::Bench {
+            day,
+            part,
+            samples,
+            answers,
+        } => bench::bench(day, part, samples, answers),
+        Command::Fetch {
+            day,
+            refresh,
+            output,
+        } => fetch::fetch(day, refresh, output),
+        Command::Submit { day, part, input } => submit::submit(day, part, input),
+        Command::New { day } => scaffold::new(day),
+        Command::Gen {
+            day,
+            seed,
+            size,
+            output,
+        } => gen::gen(day, seed, size, output),
+        Command::Step { day, input } => step::step(day, input),
+        Command::Compare {
+            day,
+            dir,
+            part,
+            format,
+            timeout,
+            memory_limit,
+            jobs,
+        } => compare::compare(
+            day,
+            dir,
+            part,
+            format,
+            child::Limits {
+                timeout: Duration::from_secs(timeout),
+                memory: Some(memory_limit << 20),
+            },
+            jobs,
+        ),
+        Command::Serve {
+            port,
+            timeout,
+            memory_limit,
+            jobs,
+        } => serve::serve(
+            port,
+            child::Limits {
+                timeout: Duration::from_secs(timeout),
+                memory: Some(memory_limit << 20),
+            },
+            jobs,
+        ),
+        Command::Watch {
+            day,
+            part,
+            timeout,
+            release,
+        } => watch::watch(day, part, Duration::from_secs(timeout), release),
+    }
+}
+
+/// The root of the workspace this runner was built in.
+pub fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+fn list() {
+    println!("{:<5}{:<6}Crate", "Day", "Part");
+
+    for solver in registry::SOLVERS {
+        println!("{:<5}{:<6}{}", solver.day, solver.part, solver.crate_name);
+    }
+}
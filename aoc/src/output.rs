@@ -0,0 +1,139 @@
+//! How the runner prints answers. The text format is for people, while the
+//! JSON format prints one object per line with the same schema for every day.
+
+use crate::registry::Solver;
+use aoc_common::memory::Stats;
+use aoc_common::solution::Timings;
+use aoc_common::Answer;
+use clap::ValueEnum;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+/// The JSON object printed for each part that was run.
+#[derive(Debug, Serialize)]
+pub struct PartReport {
+    pub day: u8,
+    pub part: u8,
+    /// The answer, always as a string so that large numbers survive JSON
+    /// parsers that read every number as a float.
+    pub answer: String,
+    /// One of `unsigned`, `signed` or `text`, saying how to read `answer`.
+    pub answer_type: &'static str,
+    pub parse_ns: u128,
+    pub solve_ns: u128,
+    /// What each phase allocated, only there when the runner was built with
+    /// the `count-allocations` feature.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory: Option<MemoryReport>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MemoryReport {
+    pub parse: PhaseMemory,
+    pub solve: PhaseMemory,
+    /// What was never freed, see [`aoc_common::solution::Memory::leaked`].
+    pub leaked_bytes: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PhaseMemory {
+    pub peak_bytes: usize,
+    pub allocations: usize,
+    pub allocated_bytes: usize,
+}
+
+impl From<Stats> for PhaseMemory {
+    fn from(stats: Stats) -> Self {
+        Self {
+            peak_bytes: stats.peak,
+            allocations: stats.allocations,
+            allocated_bytes: stats.bytes,
+        }
+    }
+}
+
+impl PartReport {
+    pub fn new(solver: &Solver, answer: &Answer, timings: &Timings) -> Self {
+        Self {
+            day: solver.day,
+            part: solver.part.number(),
+            answer: answer.to_string(),
+            answer_type: answer.type_name(),
+            parse_ns: timings.parse.as_nanos(),
+            solve_ns: timings.solve.as_nanos(),
+            memory: timings.memory.map(|memory| MemoryReport {
+                parse: memory.parse.into(),
+                solve: memory.solve.into(),
+                leaked_bytes: memory.leaked,
+            }),
+        }
+    }
+}
+
+pub fn print_answer(format: Format, solver: &Solver, answer: &Answer, timings: &Timings) {
+    match format {
+        Format::Text => {
+            println!("Day {} Part {}: {answer}", solver.day, solver.part);
+
+            if let Some(memory) = timings.memory {
+                println!("  Parse: {}", describe_memory(&memory.parse));
+                println!("  Solve: {}", describe_memory(&memory.solve));
+                println!("  Leaked: {}", format_bytes(memory.leaked));
+            }
+        }
+        Format::Json => {
+            let report = PartReport::new(solver, answer, timings);
+
+            println!(
+                "{}",
+                serde_json::to_string(&report).expect("A report should always serialize.")
+            );
+        }
+    }
+}
+
+/// A size in bytes, in the largest unit that keeps it above one.
+pub fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{size:.1} {}", UNITS[unit])
+}
+
+fn describe_memory(stats: &Stats) -> String {
+    format!(
+        "peak {}, {} allocations, {} allocated",
+        format_bytes(stats.peak),
+        stats.allocations,
+        format_bytes(stats.bytes)
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(2533443296), "2.4 GiB");
+    }
+}
@@ -0,0 +1,165 @@
+//! Every solver the runner knows about. Some days keep their parts in separate
+//! crates (like `day_1_p_2`), so each part is registered on its own.
+
+use aoc_common::solution::{self, RunError, Timings};
+use aoc_common::{Answer, Cancelled, ParseError, Part, Progress, Solution};
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+
+/// Parses an input and solves one part of it. See [`solution::run`].
+type RunFn = fn(&str, Part, &Progress) -> Result<Option<(Answer, Timings)>, RunError>;
+
+/// Parses an input without solving anything. See [`solution::parse`].
+type ParseFn = fn(&str) -> Result<(), ParseError>;
+
+fn parse_only<S: Solution>(input: &str) -> Result<(), ParseError> {
+    solution::parse::<S>(input).map(drop)
+}
+
+type CheckFn = fn(&str) -> Vec<String>;
+
+/// A parse error is reported by the run itself, so there's nothing to check.
+fn check_parsed<S: Solution>(input: &str) -> Vec<String> {
+    solution::parse::<S>(input)
+        .map(|input| S::check(&input))
+        .unwrap_or_default()
+}
+
+pub struct Solver {
+    pub day: u8,
+    pub part: Part,
+    /// The name of the crate the solver lives in.
+    pub crate_name: &'static str,
+    /// The `input.txt` embedded in that crate.
+    pub input: &'static str,
+    /// Parses an input and solves this part of it.
+    pub run: RunFn,
+    /// Only checks that an input parses.
+    pub parse: ParseFn,
+    /// Anything that looks wrong about an input, see [`Solution::check`].
+    pub check: CheckFn,
+}
+
+impl Solver {
+    const fn new<S: Solution>(part: Part, crate_name: &'static str, input: &'static str) -> Self {
+        Self {
+            day: S::DAY,
+            part,
+            crate_name,
+            input,
+            run: solution::run::<S>,
+            parse: parse_only::<S>,
+            check: check_parsed::<S>,
+        }
+    }
+
+    /// Parses `input` and solves this part of it, stopping if `progress` says
+    /// to. A freshly scaffolded day is registered before it's solved, so this
+    /// can be [`RunError::Unsolved`].
+    pub fn run(&self, input: &str, progress: &Progress) -> Result<(Answer, Timings), RunError> {
+        (self.run)(input, self.part, progress)?.ok_or(RunError::Unsolved)
+    }
+
+    /// Like [`Solver::run`], but catches a panic in the solver instead of
+    /// letting it take down the whole runner.
+    pub fn run_catching_panics(&self, input: &str, progress: &Progress) -> Outcome {
+        match panic::catch_unwind(AssertUnwindSafe(|| self.run(input, progress))) {
+            Ok(Ok((answer, timings))) => Outcome::Solved(answer, timings),
+            Ok(Err(RunError::Parse(e))) => Outcome::ParseFailed(e),
+            Ok(Err(RunError::Cancelled(reason))) => Outcome::Cancelled(reason),
+            Ok(Err(RunError::Unsolved)) => Outcome::Unsolved,
+            Err(payload) => Outcome::Panicked(panic_message(payload)),
+        }
+    }
+
+    /// Parses `input` without solving it, catching a panic in the parser.
+    /// Gives back the panic's message if there was one.
+    pub fn parse_catching_panics(&self, input: &str) -> Result<Result<(), ParseError>, String> {
+        panic::catch_unwind(|| (self.parse)(input)).map_err(panic_message)
+    }
+}
+
+/// The message a panic was started with.
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&str>() {
+            Ok(message) => message.to_string(),
+            Err(_) => "the solver panicked".to_string(),
+        },
+    }
+}
+
+/// How a run of a solver went.
+#[derive(Debug)]
+pub enum Outcome {
+    Solved(Answer, Timings),
+    ParseFailed(ParseError),
+    Panicked(String),
+    Cancelled(Cancelled),
+    Unsolved,
+}
+
+pub static SOLVERS: &[Solver] = &[
+    Solver::new::<day_1::Day1>(Part::One, "day_1", day_1::INPUT),
+    Solver::new::<day_1_p_2::Day1>(Part::Two, "day_1_p_2", day_1_p_2::INPUT),
+    Solver::new::<day_2::Day2>(Part::One, "day_2", day_2::INPUT),
+    Solver::new::<day_2::Day2>(Part::Two, "day_2", day_2::INPUT),
+    Solver::new::<day_3::Day3>(Part::One, "day_3", day_3::INPUT),
+    Solver::new::<day_3::Day3>(Part::Two, "day_3", day_3::INPUT),
+    Solver::new::<day_4::Day4>(Part::One, "day_4", day_4::INPUT),
+    Solver::new::<day_4::Day4>(Part::Two, "day_4", day_4::INPUT),
+    Solver::new::<day_5::Day5>(Part::One, "day_5", day_5::INPUT),
+    Solver::new::<day_5::Day5>(Part::Two, "day_5", day_5::INPUT),
+    Solver::new::<day_6::Day6>(Part::One, "day_6", day_6::INPUT),
+    Solver::new::<day_6::Day6>(Part::Two, "day_6", day_6::INPUT),
+    Solver::new::<day_7::Day7>(Part::One, "day_7", day_7::INPUT),
+    Solver::new::<day_7_p_2::Day7>(Part::Two, "day_7_p_2", day_7_p_2::INPUT),
+    Solver::new::<day_8::Day8>(Part::One, "day_8", day_8::INPUT),
+    Solver::new::<day_8_p_2::Day8>(Part::Two, "day_8_p_2", day_8_p_2::INPUT),
+    Solver::new::<day_9::Day9>(Part::One, "day_9", day_9::INPUT),
+];
+
+/// Finds the solver for a single part of a day.
+pub fn find(day: u8, part: Part) -> Option<&'static Solver> {
+    SOLVERS
+        .iter()
+        .find(|solver| solver.day == day && solver.part == part)
+}
+
+/// Returns every registered part of a day, in order.
+pub fn for_day(day: u8) -> Vec<&'static Solver> {
+    SOLVERS.iter().filter(|solver| solver.day == day).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// A day that's been scaffolded but not solved.
+    struct Unstarted;
+
+    impl Solution for Unstarted {
+        type Input = ();
+
+        const DAY: u8 = 25;
+
+        fn parse(_input: &str) -> Result<Self::Input, ParseError> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_unsolved() {
+        let solver = Solver::new::<Unstarted>(Part::One, "day_25", "");
+
+        assert_eq!(
+            solver.run("", &Progress::new()).unwrap_err(),
+            RunError::Unsolved
+        );
+        assert!(matches!(
+            solver.run_catching_panics("", &Progress::new()),
+            Outcome::Unsolved
+        ));
+    }
+}
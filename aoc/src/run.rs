@@ -0,0 +1,196 @@
+//! `aoc run`, along with the pieces other commands share with it for picking
+//! solvers and reading inputs.
+
+use crate::output::{self, Format};
+use crate::registry::{self, Solver};
+use aoc_common::input::InputSource;
+use aoc_common::progress::Update;
+use aoc_common::solution::RunError;
+use aoc_common::{Cancelled, Part, Progress};
+use indicatif::{HumanDuration, ProgressBar, ProgressDrawTarget, ProgressState, ProgressStyle};
+use std::fmt;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::sync::Once;
+use std::time::Duration;
+
+/// What `aoc run` exits with when a part is stopped at its deadline, the same
+/// as the `timeout` command, so that a parent process can tell it apart from
+/// a part that failed.
+pub const TIMED_OUT_EXIT_CODE: u8 = 124;
+
+/// What `aoc run` exits with when a part hasn't been solved yet, once the
+/// other parts have run.
+pub const UNSOLVED_EXIT_CODE: u8 = 3;
+
+/// How a part that hasn't said how much it has to do is shown.
+fn spinner_style() -> ProgressStyle {
+    ProgressStyle::with_template("{spinner} {msg}: {human_pos} done in {elapsed}")
+        .expect("The template should be valid.")
+}
+
+/// How a part that knows how much it has to do is shown. Until it's done
+/// something there's nothing to estimate the time left from.
+fn bar_style() -> ProgressStyle {
+    ProgressStyle::with_template("{msg} [{bar:30}] {percent}%, {left} left")
+        .expect("The template should be valid.")
+        .with_key("left", |state: &ProgressState, w: &mut dyn fmt::Write| {
+            let _ = match state.pos() {
+                0 => write!(w, "?"),
+                _ => write!(w, "{:#}", HumanDuration(state.eta())),
+            };
+        })
+        .progress_chars("=> ")
+}
+
+/// A bar on stderr for a part to report its progress on. It isn't drawn until
+/// the part first reports, so parts that don't report never show it.
+fn progress_bar(solver: &Solver) -> ProgressBar {
+    ProgressBar::with_draw_target(None, ProgressDrawTarget::stderr())
+        .with_style(spinner_style())
+        .with_message(format!("Day {} part {}", solver.day, solver.part))
+}
+
+/// Moves `bar` along to `update`. Once the part knows how much it has to do,
+/// the spinner turns into a bar with an estimate of how long is left.
+fn show_update(bar: &ProgressBar, update: Update) {
+    if bar.length() != update.total {
+        match update.total {
+            Some(total) => {
+                bar.set_style(bar_style());
+                bar.set_length(total);
+            }
+            None => {
+                bar.set_style(spinner_style());
+                bar.unset_length();
+            }
+        }
+    }
+
+    bar.set_position(update.done);
+}
+
+/// Runs each part of a day, stopping a part that's still going after
+/// `timeout` and, with `show_progress`, drawing a bar for parts that report
+/// how far they've got.
+pub fn run(
+    day: u8,
+    part: Option<u8>,
+    input: Option<PathBuf>,
+    format: Format,
+    timeout: Option<Duration>,
+    show_progress: bool,
+) -> ExitCode {
+    let Some(solvers) = select_solvers(day, part) else {
+        return ExitCode::FAILURE;
+    };
+
+    let Ok(given_input) = read_given_input(input) else {
+        return ExitCode::FAILURE;
+    };
+
+    let mut exit_code = ExitCode::SUCCESS;
+
+    for solver in solvers {
+        let input = given_input.as_deref().unwrap_or(solver.input);
+
+        let mut progress = Progress::new();
+
+        if let Some(timeout) = timeout {
+            progress = progress.with_timeout(timeout);
+        }
+
+        let bar = show_progress.then(|| progress_bar(solver));
+
+        if let Some(bar) = &bar {
+            let bar = bar.clone();
+            let ticking = Once::new();
+
+            // The spinner keeps turning between reports, so that a slow part
+            // still looks alive.
+            progress = progress.on_update(move |update| {
+                ticking.call_once(|| bar.enable_steady_tick(Duration::from_millis(100)));
+                show_update(&bar, update);
+            });
+        }
+
+        let solved = solver.run(input, &progress);
+
+        if let Some(bar) = bar {
+            bar.finish_and_clear();
+        }
+
+        match solved {
+            Ok((answer, timings)) => output::print_answer(format, solver, &answer, &timings),
+            Err(RunError::Cancelled(Cancelled::TimedOut)) => {
+                let timeout = timeout.expect("Only a part with a timeout can time out.");
+
+                eprintln!(
+                    "error: day {} part {} was still going after {timeout:.0?}, so it was stopped",
+                    solver.day, solver.part
+                );
+                return ExitCode::from(TIMED_OUT_EXIT_CODE);
+            }
+            // A day that's only just been started shouldn't stop the parts
+            // that have been solved.
+            Err(RunError::Unsolved) => {
+                eprintln!(
+                    "Day {} Part {} hasn't been solved yet.",
+                    solver.day, solver.part
+                );
+                exit_code = ExitCode::from(UNSOLVED_EXIT_CODE);
+            }
+            Err(e) => {
+                eprint!("{}", e.render(input));
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    exit_code
+}
+
+/// Picks the solvers for a day, or just one of its parts. Prints a message and
+/// returns `None` if there aren't any.
+pub fn select_solvers(day: u8, part: Option<u8>) -> Option<Vec<&'static Solver>> {
+    let solvers = match part {
+        Some(part) => Part::new(part)
+            .and_then(|part| registry::find(day, part))
+            .into_iter()
+            .collect(),
+        None => registry::for_day(day),
+    };
+
+    if solvers.is_empty() {
+        match part {
+            Some(part) => eprintln!("There is no solver for day {day} part {part}."),
+            None => eprintln!("There is no solver for day {day}."),
+        }
+
+        return None;
+    }
+
+    Some(solvers)
+}
+
+/// Reads the input given with `--input`, if there was one. Without one, each
+/// solver falls back to the input embedded in its own crate. Prints a message
+/// if the input can't be read.
+pub fn read_given_input(input: Option<PathBuf>) -> Result<Option<String>, ()> {
+    let Some(path) = input else {
+        return Ok(None);
+    };
+
+    let source = match path.as_os_str() == "-" {
+        true => InputSource::Stdin,
+        false => InputSource::File(path),
+    };
+
+    match source.read("") {
+        Ok(input) => Ok(Some(input)),
+        Err(e) => {
+            eprintln!("Failed to read the puzzle input from {source:?}: {e}");
+            Err(())
+        }
+    }
+}
@@ -0,0 +1,252 @@
+//! `aoc run --all`, which runs every registered part at once and prints a
+//! table of how each went. Every part runs in its own process (see
+//! [`child`]), so one that panics, hangs or runs out of memory is reported as
+//! such while the rest carry on.
+
+use crate::answers::{self, AnswerBook};
+use crate::child::{self, Limits, PartRun};
+use crate::output::Format;
+use crate::registry::{self, Solver};
+use serde::Serialize;
+use std::process::ExitCode;
+use std::thread;
+use std::time::Instant;
+
+/// How a part's run compares to the known answer for its input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Ok,
+    Wrong,
+    /// There's no known answer for this input to compare with.
+    Unchecked,
+    Failed,
+    TimedOut,
+    /// The part hasn't been solved yet, like a day that's only just been
+    /// started.
+    Unsolved,
+}
+
+impl Status {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Ok => "ok",
+            Self::Wrong => "wrong",
+            Self::Unchecked => "unchecked",
+            Self::Failed => "failed",
+            Self::TimedOut => "timed out",
+            Self::Unsolved => "unsolved",
+        }
+    }
+
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Self::Wrong | Self::Failed | Self::TimedOut)
+    }
+
+    pub fn of(run: &PartRun, expected: Option<&str>) -> Self {
+        match (run, expected) {
+            (PartRun::Solved { answer, .. }, Some(expected)) if answer == expected => Self::Ok,
+            (PartRun::Solved { .. }, Some(_)) => Self::Wrong,
+            (PartRun::Solved { .. }, None) => Self::Unchecked,
+            (PartRun::Failed(_), _) => Self::Failed,
+            (PartRun::TimedOut, _) => Self::TimedOut,
+            (PartRun::Unsolved, _) => Self::Unsolved,
+        }
+    }
+}
+
+/// The JSON object printed for each part with `--format json`.
+#[derive(Debug, Serialize)]
+struct AllReport {
+    day: u8,
+    part: u8,
+    status: &'static str,
+    answer: Option<String>,
+    expected: Option<String>,
+    parse_ns: Option<u128>,
+    solve_ns: Option<u128>,
+    /// Why the part failed, if it did.
+    message: Option<String>,
+}
+
+fn print_table(solvers: &[Solver], runs: &[PartRun], statuses: &[Status], book: &AnswerBook) {
+    println!(
+        "{:<5}{:<6}{:<11}{:<24}{:>12}{:>12}",
+        "Day", "Part", "Status", "Answer", "Parse", "Solve"
+    );
+
+    for ((solver, run), status) in solvers.iter().zip(runs).zip(statuses) {
+        let (answer, parse, solve) = match run {
+            PartRun::Solved {
+                answer,
+                parse,
+                solve,
+            } => (
+                answer.clone(),
+                format!("{parse:.2?}"),
+                format!("{solve:.2?}"),
+            ),
+            PartRun::Failed(message) => (message.clone(), String::new(), String::new()),
+            PartRun::TimedOut | PartRun::Unsolved => (String::new(), String::new(), String::new()),
+        };
+
+        let answer = match status {
+            Status::Wrong => format!("{answer} (expected {})", expected(book, solver).unwrap()),
+            _ => answer,
+        };
+
+        println!(
+            "{:<5}{:<6}{:<11}{:<24}{:>12}{:>12}",
+            solver.day,
+            solver.part,
+            status.name(),
+            answer,
+            parse,
+            solve
+        );
+    }
+}
+
+fn print_json(solvers: &[Solver], runs: &[PartRun], statuses: &[Status], book: &AnswerBook) {
+    for ((solver, run), status) in solvers.iter().zip(runs).zip(statuses) {
+        let mut report = AllReport {
+            day: solver.day,
+            part: solver.part.number(),
+            status: status.name(),
+            answer: None,
+            expected: expected(book, solver).map(str::to_string),
+            parse_ns: None,
+            solve_ns: None,
+            message: None,
+        };
+
+        match run {
+            PartRun::Solved {
+                answer,
+                parse,
+                solve,
+            } => {
+                report.answer = Some(answer.clone());
+                report.parse_ns = Some(parse.as_nanos());
+                report.solve_ns = Some(solve.as_nanos());
+            }
+            PartRun::Failed(message) => report.message = Some(message.clone()),
+            PartRun::TimedOut | PartRun::Unsolved => {}
+        }
+
+        println!(
+            "{}",
+            serde_json::to_string(&report).expect("A report should always serialize.")
+        );
+    }
+}
+
+fn expected<'a>(book: &'a AnswerBook, solver: &Solver) -> Option<&'a str> {
+    book.get(
+        solver.day,
+        solver.part.number(),
+        &answers::hash_input(solver.input),
+    )
+}
+
+pub fn run_all(format: Format, limits: Limits, jobs: usize) -> ExitCode {
+    let answers_path = answers::default_path();
+    let book = match AnswerBook::load(answers_path.clone()) {
+        Ok(book) => book,
+        Err(e) => {
+            eprintln!(
+                "Failed to load the known answers from {}: {e}",
+                answers_path.display()
+            );
+            return ExitCode::FAILURE;
+        }
+    };
+
+    // The parts run in copies of this binary, so that we can kill them.
+    let runner = match std::env::current_exe() {
+        Ok(runner) => runner,
+        Err(e) => {
+            eprintln!("Failed to find the runner to run the parts with: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let started = Instant::now();
+    let solvers = registry::SOLVERS;
+    let runs = child::run_concurrently(solvers, jobs, |solver| {
+        child::run_part(&runner, solver, None, limits)
+    });
+    let elapsed = started.elapsed();
+
+    let statuses = solvers
+        .iter()
+        .zip(&runs)
+        .map(|(solver, run)| Status::of(run, expected(&book, solver)))
+        .collect::<Vec<_>>();
+
+    match format {
+        Format::Text => {
+            print_table(solvers, &runs, &statuses, &book);
+
+            let counts = [
+                Status::Ok,
+                Status::Wrong,
+                Status::Unchecked,
+                Status::Failed,
+                Status::TimedOut,
+                Status::Unsolved,
+            ]
+            .map(|counted| {
+                let count = statuses.iter().filter(|status| **status == counted).count();
+
+                format!("{count} {}", counted.name())
+            });
+
+            println!(
+                "\n{} in {:.1?}, stopping parts after {:.0?}",
+                counts.join(", "),
+                elapsed,
+                limits.timeout
+            );
+        }
+        Format::Json => print_json(solvers, &runs, &statuses, &book),
+    }
+
+    match statuses.iter().any(Status::is_failure) {
+        true => ExitCode::FAILURE,
+        false => ExitCode::SUCCESS,
+    }
+}
+
+/// The default for `--jobs`, one part per core.
+pub fn default_jobs() -> usize {
+    thread::available_parallelism().map_or(1, |cores| cores.get())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_status() {
+        let solved = |answer: &str| PartRun::Solved {
+            answer: answer.to_string(),
+            parse: Duration::ZERO,
+            solve: Duration::ZERO,
+        };
+
+        assert_eq!(Status::of(&solved("42"), Some("42")), Status::Ok);
+        assert_eq!(Status::of(&solved("43"), Some("42")), Status::Wrong);
+        assert_eq!(Status::of(&solved("43"), None), Status::Unchecked);
+        assert_eq!(
+            Status::of(&PartRun::Failed("oops".to_string()), Some("42")),
+            Status::Failed
+        );
+        assert_eq!(Status::of(&PartRun::TimedOut, None), Status::TimedOut);
+        assert_eq!(Status::of(&PartRun::Unsolved, Some("42")), Status::Unsolved);
+
+        assert!(!Status::Unchecked.is_failure());
+        assert!(!Status::Unsolved.is_failure());
+        assert!(Status::Wrong.is_failure());
+    }
+}
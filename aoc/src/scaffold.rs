@@ -0,0 +1,211 @@
+//! `aoc new`, which creates the crate for a new day from the templates in
+//! `aoc/templates` and wires it into the workspace, the runner and the
+//! benchmarks.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::process::ExitCode;
+
+/// The files of a new day crate, relative to its directory. `{{day}}` is
+/// replaced with the day's number.
+const TEMPLATES: &[(&str, &str)] = &[
+    (
+        "Cargo.toml",
+        include_str!("../templates/Cargo.toml.template"),
+    ),
+    (
+        "src/lib.rs",
+        include_str!("../templates/src/lib.rs.template"),
+    ),
+    (
+        "src/main.rs",
+        include_str!("../templates/src/main.rs.template"),
+    ),
+    ("input.txt", ""),
+];
+
+/// Inserts `new_lines` into `text` among the lines that name a day crate right
+/// after `marker` (like `Solver::new::<day_`), keeping them in order of day.
+/// Returns `None` if no line has the marker.
+fn insert_in_day_order(text: &str, marker: &str, day: u8, new_lines: &[String]) -> Option<String> {
+    let lines = text.lines().collect::<Vec<_>>();
+
+    let day_of = |line: &str| -> Option<u8> {
+        let after = &line[line.find(marker)? + marker.len()..];
+        let digits = after
+            .find(|c: char| !c.is_ascii_digit())
+            .map_or(after, |end| &after[..end]);
+
+        digits.parse().ok()
+    };
+
+    let marked = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| day_of(line).map(|line_day| (i, line_day)))
+        .collect::<Vec<_>>();
+
+    let (first, _) = marked.first()?;
+    let position = marked
+        .iter()
+        .rev()
+        .find(|(_, line_day)| *line_day < day)
+        .map_or(*first, |(i, _)| i + 1);
+
+    let mut inserted = lines[..position].to_vec();
+    inserted.extend(new_lines.iter().map(String::as_str));
+    inserted.extend(&lines[position..]);
+
+    let mut inserted = inserted.join("\n");
+
+    if text.ends_with('\n') {
+        inserted.push('\n');
+    }
+
+    Some(inserted)
+}
+
+/// Rewrites the file at `path` with [`insert_in_day_order`].
+fn insert_into(path: &Path, marker: &str, day: u8, new_lines: &[String]) -> io::Result<()> {
+    let text = fs::read_to_string(path)?;
+
+    let inserted = insert_in_day_order(&text, marker, day, new_lines).ok_or_else(|| {
+        io::Error::other(format!(
+            "couldn't find where to register the crate in {}",
+            path.display()
+        ))
+    })?;
+
+    fs::write(path, inserted)
+}
+
+/// Creates `day_N` in the workspace at `root` and registers it everywhere a
+/// day has to be registered.
+pub fn scaffold(root: &Path, day: u8) -> io::Result<()> {
+    let name = format!("day_{day}");
+    let crate_dir = root.join(&name);
+
+    if crate_dir.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", crate_dir.display()),
+        ));
+    }
+
+    for (file, template) in TEMPLATES {
+        let path = crate_dir.join(file);
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        fs::write(path, template.replace("{{day}}", &day.to_string()))?;
+    }
+
+    insert_into(
+        &root.join("Cargo.toml"),
+        "\"day_",
+        day,
+        &[format!("    \"{name}\",")],
+    )?;
+    insert_into(
+        &root.join("aoc").join("Cargo.toml"),
+        "day_",
+        day,
+        &[format!("{name} = {{ path = \"../{name}\" }}")],
+    )?;
+    insert_into(
+        &root.join("aoc").join("src").join("registry.rs"),
+        "Solver::new::<day_",
+        day,
+        &[
+            format!("    Solver::new::<{name}::Day{day}>(Part::One, \"{name}\", {name}::INPUT),"),
+            format!("    Solver::new::<{name}::Day{day}>(Part::Two, \"{name}\", {name}::INPUT),"),
+        ],
+    )?;
+    insert_into(
+        &root.join("aoc").join("benches").join("solvers.rs"),
+        "bench_solution::<day_",
+        day,
+        &[format!(
+            "    bench_solution::<{name}::Day{day}>(c, \"{name}\", {name}::INPUT, &[Part::One, Part::Two]);"
+        )],
+    )
+}
+
+pub fn new(day: u8) -> ExitCode {
+    if !(1..=25).contains(&day) {
+        eprintln!("There is no day {day}, only days 1 to 25.");
+        return ExitCode::FAILURE;
+    }
+
+    if let Err(e) = scaffold(&crate::workspace_root(), day) {
+        eprintln!("Failed to create day {day}: {e}");
+        return ExitCode::FAILURE;
+    }
+
+    println!("Created day_{day}. Fetch its input with `aoc fetch {day}`, then fill in");
+    println!("`day_{day}/src/lib.rs` and the example tests at the bottom of it.");
+
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_insert_in_day_order() {
+        let members =
+            "members = [\n    \"aoc\",\n    \"day_1\",\n    \"day_1_p_2\",\n    \"day_9\",\n]\n";
+
+        assert_eq!(
+            insert_in_day_order(members, "\"day_", 5, &["    \"day_5\",".to_string()]).unwrap(),
+            "members = [\n    \"aoc\",\n    \"day_1\",\n    \"day_1_p_2\",\n    \"day_5\",\n    \"day_9\",\n]\n"
+        );
+        assert_eq!(
+            insert_in_day_order(members, "\"day_", 10, &["    \"day_10\",".to_string()]).unwrap(),
+            "members = [\n    \"aoc\",\n    \"day_1\",\n    \"day_1_p_2\",\n    \"day_9\",\n    \"day_10\",\n]\n"
+        );
+        assert_eq!(
+            insert_in_day_order("[dependencies]\n", "day_", 3, &[]),
+            None
+        );
+    }
+
+    #[test]
+    fn test_scaffold() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+
+        for file in [
+            "Cargo.toml",
+            "aoc/Cargo.toml",
+            "aoc/src/registry.rs",
+            "aoc/benches/solvers.rs",
+        ] {
+            let path = root.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::copy(crate::workspace_root().join(file), path).unwrap();
+        }
+
+        scaffold(&root, 10).unwrap();
+
+        let lib = fs::read_to_string(root.join("day_10/src/lib.rs")).unwrap();
+        assert!(lib.contains("pub struct Day10;"));
+        assert!(!lib.contains("{{day}}"));
+        assert!(!lib.contains("todo!"));
+
+        let registry = fs::read_to_string(root.join("aoc/src/registry.rs")).unwrap();
+        assert!(registry.contains(
+            "    Solver::new::<day_9::Day9>(Part::One, \"day_9\", day_9::INPUT),\n    \
+             Solver::new::<day_10::Day10>(Part::One, \"day_10\", day_10::INPUT),\n    \
+             Solver::new::<day_10::Day10>(Part::Two, \"day_10\", day_10::INPUT),\n];"
+        ));
+
+        assert!(scaffold(&root, 10).is_err());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
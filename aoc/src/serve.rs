@@ -0,0 +1,369 @@
+//! `aoc serve`, a small local HTTP server that solves the inputs posted to it,
+//! so that other tools can call the solvers without running a binary per day.
+//!
+//! `POST /day/{day}/part/{part}` with the puzzle input as the body answers
+//! with the same JSON object as `aoc run --format json`. Anything that goes
+//! wrong is answered with an error object instead, and a parse error carries
+//! the line and column it was found at.
+//!
+//! An input is parsed in the server, which is quick, so that a parse error
+//! can be pointed out. It's then solved in a child process (see [`child`])
+//! with the same limits as `aoc run --all`, so a part that panics, runs out of
+//! memory or never finishes only ever takes its own process down. A fixed
+//! number of requests are solved at once, and the rest wait their turn.
+
+use crate::child::{self, Exit, Limits};
+use crate::registry;
+use crate::run::{TIMED_OUT_EXIT_CODE, UNSOLVED_EXIT_CODE};
+use aoc_common::normalise::normalise;
+use aoc_common::{ParseError, Part};
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::io::{self, Read, Write};
+use std::path::Path;
+use std::process::ExitCode;
+use std::thread;
+use std::time::Instant;
+use tempfile::NamedTempFile;
+use tiny_http::{Header, Method, Request, Response, Server};
+
+/// The most input we'll read from a request, far more than any real input.
+const MAX_INPUT_BYTES: u64 = 16 << 20;
+
+/// The JSON object sent back with an answer.
+#[derive(Debug, Serialize)]
+struct AnswerReport {
+    /// What `aoc run --format json` printed for the part.
+    #[serde(flatten)]
+    part: Map<String, Value>,
+    /// What had to be tidied up in the input before it could be parsed, see
+    /// [`normalise`].
+    warnings: Vec<String>,
+}
+
+/// The JSON object sent back when a request can't be answered.
+#[derive(Debug, Serialize)]
+struct ErrorReport {
+    /// What kind of error it was, like `parse` or `timed out`.
+    error: &'static str,
+    message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    diagnostic: Option<Diagnostic>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    warnings: Vec<String>,
+}
+
+/// Where a parse error is in the posted input.
+#[derive(Debug, Serialize)]
+struct Diagnostic {
+    line: usize,
+    column: usize,
+    /// The text that couldn't be parsed, empty if something was missing.
+    text: String,
+    expected: String,
+    /// The error as `aoc run` prints it, quoting the line it's on.
+    rendered: String,
+}
+
+impl Diagnostic {
+    fn new(error: &ParseError, input: &str) -> Self {
+        Self {
+            line: error.line,
+            column: error.column,
+            text: error.text.clone(),
+            expected: error.expected.clone(),
+            rendered: error.render(input),
+        }
+    }
+}
+
+/// A status code and the JSON to send with it.
+type Reply = (u16, String);
+
+fn to_json(value: &impl Serialize) -> String {
+    serde_json::to_string(value).expect("A report should always serialize.")
+}
+
+fn error(status: u16, error: &'static str, message: impl Into<String>) -> Reply {
+    (
+        status,
+        to_json(&ErrorReport {
+            error,
+            message: message.into(),
+            diagnostic: None,
+            warnings: Vec::new(),
+        }),
+    )
+}
+
+/// Reads the day and part out of a path like `/day/5/part/2`.
+fn route(url: &str) -> Option<(u8, u8)> {
+    let path = url.split('?').next().unwrap_or_default();
+
+    match path.trim_matches('/').split('/').collect::<Vec<_>>()[..] {
+        ["day", day, "part", part] => Some((day.parse().ok()?, part.parse().ok()?)),
+        _ => None,
+    }
+}
+
+/// Saves a posted input to hand to the child process solving it. The file
+/// gets a fresh name that only this user can open, and it's deleted once
+/// it's dropped.
+fn save_input(input: &str) -> io::Result<NamedTempFile> {
+    let mut file = tempfile::Builder::new()
+        .prefix("aoc-serve-")
+        .suffix(".txt")
+        .tempfile()?;
+
+    file.write_all(input.as_bytes())?;
+
+    Ok(file)
+}
+
+/// Works out the reply to a request, solving the input in `body` with
+/// `runner`, a build of this binary, if it's asking for an answer.
+fn respond(method: &Method, url: &str, body: Vec<u8>, runner: &Path, limits: Limits) -> Reply {
+    let Some((day, part)) = route(url) else {
+        return error(404, "not found", "Post inputs to /day/{day}/part/{part}.");
+    };
+
+    if *method != Method::Post {
+        return error(405, "method not allowed", "Post the input as the body.");
+    }
+
+    let Some(solver) = Part::new(part).and_then(|part| registry::find(day, part)) else {
+        return error(
+            404,
+            "not found",
+            format!("There is no solver for day {day} part {part}."),
+        );
+    };
+
+    let Ok(input) = String::from_utf8(body) else {
+        return error(400, "bad input", "The input isn't valid UTF-8.");
+    };
+
+    // The solver normalises the input itself, this is only to say what it
+    // had to change.
+    let warnings = normalise(&input)
+        .quirks
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+
+    match solver.parse_catching_panics(&input) {
+        Ok(Ok(())) => (),
+        Ok(Err(e)) => {
+            return (
+                422,
+                to_json(&ErrorReport {
+                    error: "parse",
+                    message: e.to_string(),
+                    diagnostic: Some(Diagnostic::new(&e, &input)),
+                    warnings,
+                }),
+            )
+        }
+        Err(message) => return error(500, "panicked", message),
+    }
+
+    let file = match save_input(&input) {
+        Ok(file) => file,
+        Err(e) => return error(500, "failed", format!("Failed to save the input: {e}")),
+    };
+
+    let exit = child::run_part_exit(runner, solver, Some(file.path()), limits);
+    drop(file);
+
+    let timed_out = || {
+        error(
+            504,
+            "timed out",
+            format!(
+                "Day {day} part {part} was still going after {:.0?}.",
+                limits.timeout
+            ),
+        )
+    };
+
+    match exit {
+        Ok(Exit::Finished {
+            success: true,
+            stdout,
+            ..
+        }) => match serde_json::from_str(stdout.trim()) {
+            Ok(part) => (200, to_json(&AnswerReport { part, warnings })),
+            Err(e) => error(500, "failed", format!("Couldn't read the answer: {e}")),
+        },
+        Ok(Exit::Finished { code, .. }) if code == Some(TIMED_OUT_EXIT_CODE.into()) => timed_out(),
+        Ok(Exit::Finished { code, .. }) if code == Some(UNSOLVED_EXIT_CODE.into()) => error(
+            404,
+            "not found",
+            format!("Day {day} part {part} hasn't been solved yet."),
+        ),
+        // The input parsed, so this is a panic or running out of memory.
+        Ok(Exit::Finished { stderr, .. }) => error(500, "failed", child::failure_message(&stderr)),
+        Ok(Exit::TimedOut) => timed_out(),
+        Err(e) => error(500, "failed", format!("Couldn't start the runner: {e}")),
+    }
+}
+
+fn handle(mut request: Request, runner: &Path, limits: Limits) {
+    let started = Instant::now();
+
+    let mut body = Vec::new();
+    let read = request
+        .as_reader()
+        .take(MAX_INPUT_BYTES + 1)
+        .read_to_end(&mut body);
+
+    let (status, reply) = match read {
+        Err(e) => error(400, "bad input", format!("Failed to read the input: {e}")),
+        Ok(_) if body.len() as u64 > MAX_INPUT_BYTES => error(
+            413,
+            "too large",
+            format!("Inputs can be at most {MAX_INPUT_BYTES} bytes."),
+        ),
+        Ok(_) => respond(request.method(), request.url(), body, runner, limits),
+    };
+
+    println!(
+        "{} {} {status} in {:.2?}",
+        request.method(),
+        request.url(),
+        started.elapsed()
+    );
+
+    let content_type = Header::from_bytes("Content-Type", "application/json")
+        .expect("The header should be valid.");
+    let response = Response::from_string(reply)
+        .with_status_code(status)
+        .with_header(content_type);
+
+    if let Err(e) = request.respond(response) {
+        eprintln!("Failed to send the reply: {e}");
+    }
+}
+
+/// Answers requests on `port`, solving up to `jobs` of them at once, each part
+/// in a child process held to `limits`.
+pub fn serve(port: u16, limits: Limits, jobs: usize) -> ExitCode {
+    let runner = match std::env::current_exe() {
+        Ok(runner) => runner,
+        Err(e) => {
+            eprintln!("Failed to find the runner to run the parts with: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let server = match Server::http(("127.0.0.1", port)) {
+        Ok(server) => server,
+        Err(e) => {
+            eprintln!("Failed to listen on port {port}: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    println!(
+        "Listening on http://{}, post inputs to /day/{{day}}/part/{{part}}.",
+        server.server_addr()
+    );
+
+    // Each worker takes the next request once it's done with its last, so
+    // there are never more than `jobs` parts running.
+    thread::scope(|scope| {
+        for _ in 0..jobs.max(1) {
+            scope.spawn(|| {
+                for request in server.incoming_requests() {
+                    handle(request, &runner, limits);
+                }
+            });
+        }
+    });
+
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::fs;
+    use std::time::Duration;
+
+    const LIMITS: Limits = Limits {
+        timeout: Duration::from_secs(10),
+        memory: None,
+    };
+
+    /// Only requests that are answered before a part is solved can be tested
+    /// here, the rest need the runner binary, see `tests/serve.rs`.
+    fn post(url: &str, body: &[u8]) -> (u16, serde_json::Value) {
+        let runner = Path::new("no-runner");
+        let (status, reply) = respond(&Method::Post, url, body.to_vec(), runner, LIMITS);
+
+        (status, serde_json::from_str(&reply).unwrap())
+    }
+
+    #[test]
+    fn test_route() {
+        assert_eq!(route("/day/5/part/2"), Some((5, 2)));
+        assert_eq!(route("/day/12/part/1/?pretty"), Some((12, 1)));
+        assert_eq!(route("/day/five/part/1"), None);
+        assert_eq!(route("/days"), None);
+    }
+
+    #[test]
+    fn test_respond() {
+        let (status, reply) = post("/day/4/part/1", b"Card 1 1 2\r\n");
+        assert_eq!(status, 422);
+        assert_eq!(reply["error"], "parse");
+        assert_eq!(
+            (&reply["diagnostic"]["line"], &reply["diagnostic"]["column"]),
+            (&1.into(), &11.into())
+        );
+        assert_eq!(reply["warnings"].as_array().unwrap().len(), 1);
+
+        assert_eq!(post("/day/30/part/1", b"").0, 404);
+        assert_eq!(post("/solve", b"").0, 404);
+        assert_eq!(post("/day/6/part/1", &[0xff]).0, 400);
+        assert_eq!(
+            respond(
+                &Method::Get,
+                "/day/6/part/1",
+                Vec::new(),
+                Path::new("no-runner"),
+                LIMITS
+            )
+            .0,
+            405
+        );
+
+        // An input that parses is handed to the runner.
+        let (status, reply) = post("/day/6/part/1", b"Time: 7\nDistance: 9\n");
+        assert_eq!((status, &reply["error"]), (500, &"failed".into()));
+        assert!(reply["message"]
+            .as_str()
+            .unwrap()
+            .starts_with("Couldn't start the runner"));
+    }
+
+    #[test]
+    fn test_save_input() {
+        let file = save_input("0 3 6 9 12 15\n").unwrap();
+        let path = file.path().to_path_buf();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "0 3 6 9 12 15\n");
+        assert_ne!(path, save_input("").unwrap().path());
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+
+            let mode = fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o077, 0);
+        }
+
+        drop(file);
+        assert!(!path.exists());
+    }
+}
@@ -0,0 +1,475 @@
+//! The Advent of Code site itself. Every request goes through [`Site`], which
+//! sends the session cookie and makes sure requests are spaced out by at
+//! least the configured throttle, even across separate runs of the binary.
+
+use crate::config::Config;
+use aoc_common::{Answer, Part};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use ureq::Agent;
+
+pub const YEAR: u16 = 2023;
+
+/// Who we are, as the site asks automated tools to say.
+const USER_AGENT: &str = concat!("AoC-2023 aoc runner/", env!("CARGO_PKG_VERSION"));
+
+/// Midnight EST on the 1st of December 2023, when the first puzzle unlocked.
+const FIRST_UNLOCK: u64 = 1_701_406_800;
+
+/// When the given day's puzzle unlocks. Puzzles come out at midnight EST,
+/// one a day.
+pub fn unlocks_at(day: u8) -> SystemTime {
+    let day_offset = (day.max(1) as u64 - 1) * 24 * 60 * 60;
+
+    UNIX_EPOCH + Duration::from_secs(FIRST_UNLOCK + day_offset)
+}
+
+/// Checks that `day` is a real puzzle and has unlocked by `now`.
+pub fn check_unlocked(day: u8, now: SystemTime) -> Result<(), SiteError> {
+    if !(1..=25).contains(&day) {
+        return Err(SiteError::NoSuchDay(day));
+    }
+
+    match unlocks_at(day).duration_since(now) {
+        Ok(wait) if !wait.is_zero() => Err(SiteError::Locked(day, wait)),
+        _ => Ok(()),
+    }
+}
+
+#[derive(Debug)]
+pub enum SiteError {
+    NoSuchDay(u8),
+    /// The puzzle unlocks after this long.
+    Locked(u8, Duration),
+    NoSession,
+    /// The site answered, but not with what we asked for.
+    Status(u16, String),
+    /// The site answered a submission with a page we don't understand.
+    UnknownReply(String),
+    Http(ureq::Error),
+    Io(io::Error),
+}
+
+impl fmt::Display for SiteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoSuchDay(day) => write!(f, "there is no day {day}, only days 1 to 25"),
+            Self::Locked(day, wait) => {
+                let minutes = wait.as_secs().div_ceil(60);
+
+                write!(
+                    f,
+                    "day {day} hasn't unlocked yet, it unlocks in {}h {}m",
+                    minutes / 60,
+                    minutes % 60
+                )
+            }
+            Self::NoSession => write!(
+                f,
+                "no session token, set `AOC_SESSION` or `session` in the config file"
+            ),
+            Self::Status(400, _) => write!(f, "the site rejected the session token (400)"),
+            Self::Status(404, _) => write!(f, "the site has no such page yet (404)"),
+            Self::Status(status, body) => {
+                write!(f, "the site answered with {status}: {}", body.trim())
+            }
+            Self::UnknownReply(message) => {
+                write!(f, "the site said something unexpected: {message}")
+            }
+            Self::Http(e) => write!(f, "failed to reach the site: {e}"),
+            Self::Io(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl std::error::Error for SiteError {}
+
+impl From<ureq::Error> for SiteError {
+    fn from(e: ureq::Error) -> Self {
+        Self::Http(e)
+    }
+}
+
+impl From<io::Error> for SiteError {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+/// What the site made of a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without saying which way.
+    Wrong,
+    /// We answered too recently, and have to wait this long to try again.
+    Wait {
+        seconds: u64,
+    },
+    /// The part was solved already, or the part before it hasn't been yet.
+    AlreadySolved,
+}
+
+impl Verdict {
+    /// Reads the verdict out of the page the site sends back for an answer.
+    pub fn parse(page: &str) -> Result<Self, SiteError> {
+        let message = article_text(page);
+
+        if message.contains("That's the right answer") {
+            Ok(Self::Correct)
+        } else if message.contains("That's not the right answer") {
+            if message.contains("too high") {
+                Ok(Self::TooHigh)
+            } else if message.contains("too low") {
+                Ok(Self::TooLow)
+            } else {
+                Ok(Self::Wrong)
+            }
+        } else if message.contains("You gave an answer too recently") {
+            Ok(Self::Wait {
+                seconds: parse_wait(&message).unwrap_or(60),
+            })
+        } else if message.contains("You don't seem to be solving the right level") {
+            Ok(Self::AlreadySolved)
+        } else {
+            Err(SiteError::UnknownReply(message))
+        }
+    }
+
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Self::TooHigh | Self::TooLow | Self::Wrong)
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Correct => write!(f, "that's the right answer"),
+            Self::TooHigh => write!(f, "that's not the right answer, it's too high"),
+            Self::TooLow => write!(f, "that's not the right answer, it's too low"),
+            Self::Wrong => write!(f, "that's not the right answer"),
+            Self::Wait { seconds } => {
+                write!(f, "an answer was given too recently, wait {seconds}s")
+            }
+            Self::AlreadySolved => write!(
+                f,
+                "that part is already solved, or the one before it isn't yet"
+            ),
+        }
+    }
+}
+
+/// The text of the `<article>` in a page, which is where the site puts its
+/// message, with the tags stripped out.
+fn article_text(page: &str) -> String {
+    let start = page.find("<article>").unwrap_or(0);
+    let end = page[start..]
+        .find("</article>")
+        .map_or(page.len(), |end| start + end);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+
+    for character in page[start..end].chars() {
+        match character {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(character),
+            _ => {}
+        }
+    }
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Reads how long to wait from a message like "You have 1m 23s left to wait."
+fn parse_wait(message: &str) -> Option<u64> {
+    let start = message.find("You have ")? + "You have ".len();
+    let end = start + message[start..].find(" left to wait")?;
+
+    message[start..end]
+        .split_whitespace()
+        .map(|amount| {
+            let (number, unit) = amount.split_at(amount.len().checked_sub(1)?);
+            let number: u64 = number.parse().ok()?;
+
+            match unit {
+                "h" => Some(number * 60 * 60),
+                "m" => Some(number * 60),
+                "s" => Some(number),
+                _ => None,
+            }
+        })
+        .sum()
+}
+
+pub struct Site {
+    agent: Agent,
+    base_url: String,
+    session: String,
+    throttle: Duration,
+    /// Touched on every request, so its modification time says when the
+    /// last one was made.
+    last_request: PathBuf,
+}
+
+impl Site {
+    pub fn new(config: &Config) -> Result<Self, SiteError> {
+        let session = config.session.clone().ok_or(SiteError::NoSession)?;
+
+        let agent = Agent::config_builder()
+            .http_status_as_error(false)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .user_agent(USER_AGENT)
+            .build()
+            .into();
+
+        Ok(Self {
+            agent,
+            base_url: config.base_url.trim_end_matches('/').to_string(),
+            session,
+            throttle: config.throttle,
+            last_request: config.cache_dir.join("last_request"),
+        })
+    }
+
+    /// Sleeps until enough time has passed since the last request, then
+    /// marks now as the time of the latest one.
+    fn wait_for_turn(&self) -> io::Result<()> {
+        let since_last = fs::metadata(&self.last_request)
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .and_then(|modified| modified.elapsed().ok());
+
+        if let Some(since_last) = since_last {
+            if let Some(wait) = self.throttle.checked_sub(since_last) {
+                eprintln!(
+                    "Waiting {:.1}s before the next request.",
+                    wait.as_secs_f32()
+                );
+                thread::sleep(wait);
+            }
+        }
+
+        if let Some(parent) = self.last_request.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        fs::write(&self.last_request, "")
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    /// Reads the body of a response, as long as it was a success.
+    fn read_body(mut response: ureq::http::Response<ureq::Body>) -> Result<String, SiteError> {
+        let status = response.status().as_u16();
+        let body = response.body_mut().read_to_string()?;
+
+        match status {
+            200 => Ok(body),
+            _ => Err(SiteError::Status(status, body)),
+        }
+    }
+
+    /// Downloads the puzzle input for `day`.
+    pub fn input(&self, day: u8) -> Result<String, SiteError> {
+        check_unlocked(day, SystemTime::now())?;
+        self.wait_for_turn()?;
+
+        let url = format!("{}/{YEAR}/day/{day}/input", self.base_url);
+        let response = self
+            .agent
+            .get(&url)
+            .header("Cookie", &self.cookie())
+            .call()?;
+
+        Self::read_body(response)
+    }
+
+    /// Submits an answer to one part of a day.
+    pub fn submit(&self, day: u8, part: Part, answer: &Answer) -> Result<Verdict, SiteError> {
+        check_unlocked(day, SystemTime::now())?;
+        self.wait_for_turn()?;
+
+        let url = format!("{}/{YEAR}/day/{day}/answer", self.base_url);
+        let response = self
+            .agent
+            .post(&url)
+            .header("Cookie", &self.cookie())
+            .send_form([
+                ("level", part.number().to_string()),
+                ("answer", answer.to_string()),
+            ])?;
+
+        Verdict::parse(&Self::read_body(response)?)
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tiny_http::{Response, Server};
+
+    /// A stand-in for the site that answers each request with `respond`,
+    /// given the method, URL, cookie and body of the request. It shuts down
+    /// once it's had no requests for a few seconds.
+    pub fn mock_site(
+        respond: impl Fn(&str, &str, &str, &str) -> (u16, String) + Send + 'static,
+    ) -> String {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", server.server_addr().to_ip().unwrap());
+
+        thread::spawn(move || {
+            while let Ok(Some(mut request)) = server.recv_timeout(Duration::from_secs(5)) {
+                let cookie = request
+                    .headers()
+                    .iter()
+                    .find(|header| header.field.equiv("Cookie"))
+                    .map(|header| header.value.to_string())
+                    .unwrap_or_default();
+                let mut body = String::new();
+                request.as_reader().read_to_string(&mut body).unwrap();
+
+                let (status, reply) =
+                    respond(request.method().as_str(), request.url(), &cookie, &body);
+
+                request
+                    .respond(Response::from_string(reply).with_status_code(status))
+                    .unwrap();
+            }
+        });
+
+        base_url
+    }
+
+    /// A config pointing at `base_url`, with its own empty cache directory.
+    pub fn test_config(base_url: String) -> Config {
+        static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+        let cache_dir = std::env::temp_dir().join(format!(
+            "aoc-test-{}-{}",
+            std::process::id(),
+            NEXT_ID.fetch_add(1, Ordering::Relaxed)
+        ));
+        let _ = fs::remove_dir_all(&cache_dir);
+
+        Config {
+            session: Some("abc123".to_string()),
+            base_url,
+            cache_dir,
+            throttle: Duration::ZERO,
+        }
+    }
+
+    #[test]
+    fn test_unlocks() {
+        let first = UNIX_EPOCH + Duration::from_secs(FIRST_UNLOCK);
+
+        assert_eq!(unlocks_at(1), first);
+        assert!(check_unlocked(1, first).is_ok());
+        assert!(matches!(
+            check_unlocked(2, first),
+            Err(SiteError::Locked(2, wait)) if wait == Duration::from_secs(24 * 60 * 60)
+        ));
+        assert!(matches!(
+            check_unlocked(26, first),
+            Err(SiteError::NoSuchDay(26))
+        ));
+    }
+
+    #[test]
+    fn test_fetch_input() {
+        let base_url = mock_site(|method, url, cookie, _| match (method, url, cookie) {
+            ("GET", "/2023/day/3/input", "session=abc123") => (200, "467..114..\n".to_string()),
+            ("GET", _, "session=abc123") => (404, "404 Not Found".to_string()),
+            _ => (400, "Puzzle inputs differ by user.".to_string()),
+        });
+        let mut config = test_config(base_url);
+        let site = Site::new(&config).unwrap();
+
+        assert_eq!(site.input(3).unwrap(), "467..114..\n");
+        assert!(matches!(site.input(25), Err(SiteError::Status(404, _))));
+
+        config.session = Some("wrong".to_string());
+        let site = Site::new(&config).unwrap();
+
+        assert!(matches!(site.input(3), Err(SiteError::Status(400, _))));
+    }
+
+    #[test]
+    fn test_parse_verdict() {
+        let page = |message: &str| format!("<main>\n<article><p>{message}</p></article>\n</main>");
+
+        assert_eq!(
+            Verdict::parse(&page(
+                "That's the right answer! You are <em>one gold star</em> closer."
+            ))
+            .unwrap(),
+            Verdict::Correct
+        );
+        assert_eq!(
+            Verdict::parse(&page("That's not the right answer; your answer is too low. If you're stuck, make sure you're using the full input data.")).unwrap(),
+            Verdict::TooLow
+        );
+        assert_eq!(
+            Verdict::parse(&page(
+                "That's not the right answer. If you're stuck, ask for help."
+            ))
+            .unwrap(),
+            Verdict::Wrong
+        );
+        assert_eq!(
+            Verdict::parse(&page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 23s left to wait. <a href=\"/2023/day/5\">[Return to Day 5]</a>")).unwrap(),
+            Verdict::Wait { seconds: 83 }
+        );
+        assert_eq!(
+            Verdict::parse(&page(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            ))
+            .unwrap(),
+            Verdict::AlreadySolved
+        );
+        assert!(matches!(
+            Verdict::parse(&page("Something else entirely.")),
+            Err(SiteError::UnknownReply(message)) if message == "Something else entirely."
+        ));
+    }
+
+    #[test]
+    fn test_submit() {
+        let base_url = mock_site(|method, url, _, body| match (method, url, body) {
+            ("POST", "/2023/day/7/answer", "level=2&answer=251515496") => (
+                200,
+                "<article><p>That's the right answer!</p></article>".to_string(),
+            ),
+            _ => (
+                200,
+                "<article><p>That's not the right answer; your answer is too high.</p></article>"
+                    .to_string(),
+            ),
+        });
+        let site = Site::new(&test_config(base_url)).unwrap();
+
+        assert_eq!(
+            site.submit(7, Part::Two, &Answer::Unsigned(251515496))
+                .unwrap(),
+            Verdict::Correct
+        );
+        assert_eq!(
+            site.submit(7, Part::Two, &Answer::Unsigned(251515497))
+                .unwrap(),
+            Verdict::TooHigh
+        );
+    }
+}
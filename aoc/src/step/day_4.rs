@@ -0,0 +1,115 @@
+//! Scratching off day 4's cards one at a time, with how many copies of each
+//! card we've won so far.
+
+use super::Simulation;
+use day_4::{Game, GameTracker};
+
+pub struct Cards(GameTracker);
+
+impl Cards {
+    pub fn new(games: Vec<Game>) -> Self {
+        Self(GameTracker::new(games))
+    }
+}
+
+impl Simulation for Cards {
+    /// A card that would win more copies than we can count ends the
+    /// simulation there.
+    fn step(&mut self) -> bool {
+        self.0.step().unwrap_or(false)
+    }
+
+    fn location(&self) -> String {
+        format!("Card {}", self.0.current_game())
+    }
+
+    /// The cards around the one we're about to scratch off, with their copies.
+    fn state(&self, height: usize) -> Vec<String> {
+        let tracker = &self.0;
+        let used = match tracker.scratchcards_used() {
+            Some(used) => used.to_string(),
+            None => "too many to count".to_string(),
+        };
+        let mut lines = vec![format!("Scratchcards so far: {used}"), String::new()];
+
+        let shown = height.saturating_sub(lines.len()).max(1);
+        let current = tracker.current_game() as usize;
+        let first = current.saturating_sub(shown / 2).max(1);
+
+        // The tracker counts cards by where they are in the table, which
+        // needn't match the ids they were given.
+        let cards = tracker.games().iter().zip(1..).skip(first - 1);
+
+        for (game, position) in cards.take(shown) {
+            let marker = match position as usize == current {
+                true => '>',
+                false => ' ',
+            };
+
+            lines.push(format!(
+                "{marker} Card {:>3}: {:>2} matches, {:>8} copies",
+                game.id,
+                game.calculate_matches(),
+                tracker.copies(position)
+            ));
+        }
+
+        lines
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::step::{Breakpoint, Stepper, Stop};
+    use aoc_common::Solution;
+    use day_4::Day4;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn test_cards() {
+        let games = Day4::parse(
+            "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1",
+        )
+        .unwrap();
+
+        let mut stepper = Stepper::new(Cards::new(games));
+        stepper
+            .breakpoints
+            .push(Breakpoint::parse("card 3").unwrap());
+
+        let deadline = Instant::now() + Duration::from_secs(60);
+        assert!(matches!(
+            stepper.run_until(deadline),
+            Some(Stop::Breakpoint(_))
+        ));
+        assert_eq!(stepper.steps, 2);
+
+        let state = stepper.simulation.state(10);
+        assert_eq!(state[0], "Scratchcards so far: 7");
+        assert_eq!(state[4], "> Card   3:  2 matches,        4 copies");
+
+        assert_eq!(stepper.run_until(deadline), Some(Stop::Finished));
+        assert_eq!(stepper.simulation.0.scratchcards_used(), Some(7));
+    }
+
+    #[test]
+    fn test_cards_out_of_order() {
+        let games = Day4::parse(
+            "Card 7: 1 2 | 1 2
+Card 3: 1 | 5
+Card 9: 1 | 1",
+        )
+        .unwrap();
+
+        let mut cards = Cards::new(games);
+        assert!(cards.step());
+
+        let state = cards.state(10);
+        assert_eq!(state[2], "  Card   7:  2 matches,        1 copies");
+        assert_eq!(state[3], "> Card   3:  0 matches,        2 copies");
+        assert_eq!(state[4], "  Card   9:  1 matches,        2 copies");
+    }
+}
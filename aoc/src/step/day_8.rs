@@ -0,0 +1,92 @@
+//! Walking day 8's network from `AAA`, one direction at a time.
+
+use super::Simulation;
+use day_8::{Network, Traversal};
+use std::rc::Rc;
+
+/// How many directions to show either side of the next one.
+const DIRECTIONS_AROUND: usize = 30;
+
+pub struct Walk<'a>(Traversal<'a>);
+
+impl<'a> Walk<'a> {
+    pub fn new(network: &'a Network) -> Self {
+        Self(Traversal::new(
+            Rc::clone(&network.starting_node),
+            &network.directions,
+        ))
+    }
+}
+
+impl Simulation for Walk<'_> {
+    fn step(&mut self) -> bool {
+        self.0.step()
+    }
+
+    fn location(&self) -> String {
+        self.0.current_node.borrow().name.clone()
+    }
+
+    /// The node we're on, and the directions around the one we follow next.
+    fn state(&self, _height: usize) -> Vec<String> {
+        let traversal = &self.0;
+        let index = traversal.direction_index;
+
+        let first = index.saturating_sub(DIRECTIONS_AROUND);
+        let last = (index + DIRECTIONS_AROUND + 1).min(traversal.directions.len());
+        let directions = traversal.directions[first..last]
+            .iter()
+            .map(ToString::to_string)
+            .collect::<String>();
+
+        vec![
+            format!("Node: {}", traversal.current_node.borrow()),
+            format!("Steps: {}", traversal.steps),
+            format!(
+                "Next direction: {} of {}",
+                index + 1,
+                traversal.directions.len()
+            ),
+            String::new(),
+            directions,
+            format!("{}^", " ".repeat(index - first)),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use aoc_common::Solution;
+    use day_8::Day8;
+
+    #[test]
+    fn test_walk() {
+        let network = Day8::parse(
+            "LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)",
+        )
+        .unwrap();
+        let mut walk = Walk::new(&network);
+
+        assert!(walk.step());
+        assert_eq!(walk.location(), "BBB");
+        assert_eq!(
+            walk.state(10),
+            [
+                "Node: BBB = (AAA, ZZZ)",
+                "Steps: 1",
+                "Next direction: 2 of 3",
+                "",
+                "LLR",
+                " ^",
+            ]
+        );
+
+        while walk.step() {}
+        assert_eq!((walk.location().as_str(), walk.0.steps), ("ZZZ", 6));
+    }
+}
@@ -0,0 +1,126 @@
+//! Building day 9's rows of differences, one row at a time, field after field.
+
+use super::Simulation;
+use day_9::Field;
+
+pub struct Pyramid {
+    fields: Vec<Field>,
+    /// The index of the field being populated.
+    index: usize,
+    field: Option<Field>,
+    /// Whether the field being populated has reached its row of zeros.
+    populated: bool,
+}
+
+impl Pyramid {
+    pub fn new(fields: Vec<Field>) -> Self {
+        let field = fields.first().cloned();
+
+        Self {
+            fields,
+            index: 0,
+            field,
+            populated: false,
+        }
+    }
+}
+
+impl Simulation for Pyramid {
+    /// Adds a row to the current field, or once that's populated, moves on to
+    /// the next field.
+    fn step(&mut self) -> bool {
+        let is_last = |pyramid: &Self| pyramid.index + 1 >= pyramid.fields.len();
+
+        if self.populated {
+            if is_last(self) {
+                return false;
+            }
+
+            self.index += 1;
+            self.field = self.fields.get(self.index).cloned();
+            self.populated = false;
+        } else if let Some(field) = &mut self.field {
+            self.populated = !field.populate_step();
+        } else {
+            return false;
+        }
+
+        !(self.populated && is_last(self))
+    }
+
+    fn location(&self) -> String {
+        format!("Field {}", self.index + 1)
+    }
+
+    /// The rows of the current field, indented so they line up under the gaps
+    /// of the row above. Only the last rows are shown if they don't all fit.
+    fn state(&self, height: usize) -> Vec<String> {
+        let Some(field) = &self.field else {
+            return vec!["There are no fields.".to_string()];
+        };
+
+        let rows = field.rows();
+        let mut lines = vec![
+            format!(
+                "Field {} of {}, {} rows",
+                self.index + 1,
+                self.fields.len(),
+                rows.len()
+            ),
+            String::new(),
+        ];
+
+        let width = rows
+            .iter()
+            .flatten()
+            .map(|number| number.to_string().len())
+            .max()
+            .unwrap_or(1)
+            + 1;
+
+        let shown = height.saturating_sub(lines.len()).max(1);
+        let first = rows.len().saturating_sub(shown);
+
+        for (depth, row) in rows.iter().enumerate().skip(first) {
+            let mut line = " ".repeat(depth * width / 2);
+
+            for number in row {
+                line.push_str(&format!("{number:>width$}"));
+            }
+
+            lines.push(line);
+        }
+
+        lines
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use aoc_common::Solution;
+    use day_9::Day9;
+
+    #[test]
+    fn test_pyramid() {
+        // Both fields are all one number, so their first rows of differences
+        // are all zeros.
+        let fields = Day9::parse("5 5 5\n-4 -4").unwrap();
+        let mut pyramid = Pyramid::new(fields);
+
+        assert!(pyramid.step());
+        assert_eq!(
+            pyramid.state(10)[..3],
+            ["Field 1 of 2, 2 rows", "", " 5 5 5"]
+        );
+        assert_eq!(pyramid.state(10)[3], "  0 0");
+
+        assert!(pyramid.step());
+        assert_eq!(pyramid.location(), "Field 2");
+        assert!(!pyramid.step());
+        assert!(!pyramid.step());
+        assert_eq!(pyramid.state(10)[2..], [" -4 -4", "   0"]);
+
+        assert!(!Pyramid::new(Vec::new()).step());
+    }
+}
@@ -0,0 +1,260 @@
+//! `aoc step`, a terminal UI for stepping through the days that work one step
+//! at a time: day 4's scratchcards, day 8's walk through the network and day
+//! 9's difference rows. It can take one step, play the steps forward, or run
+//! until it reaches a breakpoint.
+
+mod day_4;
+mod day_8;
+mod day_9;
+mod tui;
+
+use crate::run;
+use aoc_common::solution::{self, Solution};
+use std::fmt;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Instant;
+
+/// A puzzle that can be solved one step at a time.
+pub trait Simulation {
+    /// Takes the next step. Returns true if another step can be made, and
+    /// false once the simulation has finished.
+    fn step(&mut self) -> bool;
+
+    /// Where the simulation is up to, like `Card 5` or `AAA`. A breakpoint
+    /// that isn't a step number stops the simulation here.
+    fn location(&self) -> String;
+
+    /// The lines to show for the current state, fitting in `height` lines.
+    fn state(&self, height: usize) -> Vec<String>;
+}
+
+/// Where to stop running.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Breakpoint {
+    /// Stop once this many steps have been taken.
+    Step(u64),
+    /// Stop at this location, ignoring case.
+    Location(String),
+}
+
+impl Breakpoint {
+    /// A number is a step, and anything else is a location.
+    pub fn parse(text: &str) -> Option<Self> {
+        let text = text.trim();
+
+        if text.is_empty() {
+            return None;
+        }
+
+        Some(match text.parse() {
+            Ok(step) => Self::Step(step),
+            Err(_) => Self::Location(text.to_string()),
+        })
+    }
+
+    fn is_hit(&self, steps: u64, location: &str) -> bool {
+        match self {
+            Self::Step(step) => steps == *step,
+            Self::Location(wanted) => wanted.eq_ignore_ascii_case(location),
+        }
+    }
+}
+
+impl fmt::Display for Breakpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Step(step) => write!(f, "step {step}"),
+            Self::Location(location) => write!(f, "{location}"),
+        }
+    }
+}
+
+/// Why a run stopped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Stop {
+    Breakpoint(Breakpoint),
+    Finished,
+}
+
+/// A simulation along with how far it's got and where it should stop.
+pub struct Stepper<S> {
+    pub simulation: S,
+    pub steps: u64,
+    pub finished: bool,
+    pub breakpoints: Vec<Breakpoint>,
+}
+
+impl<S: Simulation> Stepper<S> {
+    pub fn new(simulation: S) -> Self {
+        Self {
+            simulation,
+            steps: 0,
+            finished: false,
+            breakpoints: Vec::new(),
+        }
+    }
+
+    /// Takes one step, unless the simulation has already finished.
+    pub fn step(&mut self) {
+        if !self.finished {
+            self.finished = !self.simulation.step();
+            self.steps += 1;
+        }
+    }
+
+    /// Takes one step and says whether that's somewhere to stop.
+    fn step_checking(&mut self) -> Option<Stop> {
+        self.step();
+
+        if self.finished {
+            return Some(Stop::Finished);
+        }
+
+        let location = self.simulation.location();
+
+        self.breakpoints
+            .iter()
+            .find(|breakpoint| breakpoint.is_hit(self.steps, &location))
+            .cloned()
+            .map(Stop::Breakpoint)
+    }
+
+    /// Steps until a breakpoint or the end, or until `deadline` passes so that
+    /// the screen can be redrawn. Returns why it stopped, or `None` if it ran
+    /// out of time.
+    pub fn run_until(&mut self, deadline: Instant) -> Option<Stop> {
+        if self.finished {
+            return Some(Stop::Finished);
+        }
+
+        loop {
+            if let Some(stop) = self.step_checking() {
+                return Some(stop);
+            }
+
+            // Checking the time every step would slow down the fast days.
+            if self.steps.is_multiple_of(256) && Instant::now() >= deadline {
+                return None;
+            }
+        }
+    }
+}
+
+/// Parses `input` for a day, printing where it went wrong if it can't.
+fn parse<S: Solution>(input: &str) -> Option<S::Input> {
+    match solution::parse::<S>(input) {
+        Ok(parsed) => Some(parsed),
+        Err(e) => {
+            eprint!("{}", e.render(input));
+            None
+        }
+    }
+}
+
+pub fn step(day: u8, input: Option<PathBuf>) -> ExitCode {
+    let Ok(given_input) = run::read_given_input(input) else {
+        return ExitCode::FAILURE;
+    };
+
+    let shown = match day {
+        4 => {
+            let input = given_input.as_deref().unwrap_or(::day_4::INPUT);
+            parse::<::day_4::Day4>(input)
+                .map(|games| tui::show(day, Stepper::new(day_4::Cards::new(games))))
+        }
+        8 => {
+            let input = given_input.as_deref().unwrap_or(::day_8::INPUT);
+            parse::<::day_8::Day8>(input)
+                .map(|network| tui::show(day, Stepper::new(day_8::Walk::new(&network))))
+        }
+        9 => {
+            let input = given_input.as_deref().unwrap_or(::day_9::INPUT);
+            parse::<::day_9::Day9>(input)
+                .map(|fields| tui::show(day, Stepper::new(day_9::Pyramid::new(fields))))
+        }
+        _ => {
+            eprintln!("Day {day} can't be stepped through, only days 4, 8 and 9.");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match shown {
+        Some(Ok(())) => ExitCode::SUCCESS,
+        Some(Err(e)) => {
+            eprintln!("The terminal stopped working: {e}");
+            ExitCode::FAILURE
+        }
+        None => ExitCode::FAILURE,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Counts up to `end`, one at a time.
+    struct Counter {
+        count: u64,
+        end: u64,
+    }
+
+    impl Simulation for Counter {
+        fn step(&mut self) -> bool {
+            self.count += 1;
+            self.count < self.end
+        }
+
+        fn location(&self) -> String {
+            format!("at {}", self.count)
+        }
+
+        fn state(&self, _height: usize) -> Vec<String> {
+            vec![self.count.to_string()]
+        }
+    }
+
+    fn counter(end: u64) -> Stepper<Counter> {
+        Stepper::new(Counter { count: 0, end })
+    }
+
+    #[test]
+    fn test_breakpoints() {
+        assert_eq!(Breakpoint::parse(" 12 "), Some(Breakpoint::Step(12)));
+        assert_eq!(
+            Breakpoint::parse("ZZZ"),
+            Some(Breakpoint::Location("ZZZ".to_string()))
+        );
+        assert_eq!(Breakpoint::parse("  "), None);
+
+        let mut stepper = counter(1000);
+        stepper.breakpoints = vec![Breakpoint::Step(300), Breakpoint::parse("AT 20").unwrap()];
+
+        let far_off = Instant::now() + std::time::Duration::from_secs(60);
+
+        assert_eq!(
+            stepper.run_until(far_off),
+            Some(Stop::Breakpoint(Breakpoint::Location("AT 20".to_string())))
+        );
+        assert_eq!(stepper.steps, 20);
+
+        assert_eq!(
+            stepper.run_until(far_off),
+            Some(Stop::Breakpoint(Breakpoint::Step(300)))
+        );
+        assert_eq!(stepper.run_until(far_off), Some(Stop::Finished));
+        assert_eq!(stepper.steps, 1000);
+
+        // Stepping past the end does nothing.
+        stepper.step();
+        assert_eq!((stepper.steps, stepper.simulation.count), (1000, 1000));
+    }
+
+    #[test]
+    fn test_run_out_of_time() {
+        let mut stepper = counter(u64::MAX);
+
+        assert_eq!(stepper.run_until(Instant::now()), None);
+        assert!(stepper.steps > 0 && !stepper.finished);
+    }
+}
@@ -0,0 +1,228 @@
+//! Drawing a [`Stepper`] in the terminal and handling the keys that drive it.
+
+use super::{Breakpoint, Simulation, Stepper, Stop};
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::widgets::{Block, Paragraph};
+use ratatui::{DefaultTerminal, Frame};
+use std::io;
+use std::time::{Duration, Instant};
+
+const HELP: &str =
+    "space: step  p: play/pause  r: run  b: add a breakpoint  c: clear them  +/-: speed  q: quit";
+
+/// How long a run goes between redraws, so keys still work while it's going.
+const RUN_SLICE: Duration = Duration::from_millis(50);
+
+const SLOWEST: Duration = Duration::from_secs(2);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Paused,
+    /// Taking a step every `delay`.
+    Playing,
+    /// Stepping as fast as possible until a breakpoint or the end.
+    Running,
+}
+
+struct App<S> {
+    day: u8,
+    stepper: Stepper<S>,
+    mode: Mode,
+    delay: Duration,
+    message: String,
+    /// The breakpoint being typed in, if there is one.
+    entering: Option<String>,
+    quit: bool,
+}
+
+impl<S: Simulation> App<S> {
+    fn stopped(&mut self, stop: Stop) {
+        self.mode = Mode::Paused;
+        self.message = match stop {
+            Stop::Breakpoint(breakpoint) => format!("Stopped at {breakpoint}."),
+            Stop::Finished => format!("Finished after {} steps.", self.stepper.steps),
+        };
+    }
+
+    fn handle_key(&mut self, code: KeyCode) {
+        if let Some(text) = &mut self.entering {
+            match code {
+                KeyCode::Char(c) => text.push(c),
+                KeyCode::Backspace => {
+                    text.pop();
+                }
+                KeyCode::Enter => {
+                    if let Some(breakpoint) = Breakpoint::parse(text) {
+                        self.message = format!("Added a breakpoint at {breakpoint}.");
+                        self.stepper.breakpoints.push(breakpoint);
+                    }
+
+                    self.entering = None;
+                }
+                KeyCode::Esc => self.entering = None,
+                _ => {}
+            }
+
+            return;
+        }
+
+        // Any other key stops a run, so a run that never ends can be stopped.
+        if self.mode == Mode::Running && !matches!(code, KeyCode::Char('q') | KeyCode::Esc) {
+            self.mode = Mode::Paused;
+            self.message = "Paused.".to_string();
+            return;
+        }
+
+        match code {
+            KeyCode::Char('q') | KeyCode::Esc => self.quit = true,
+            KeyCode::Char(' ') | KeyCode::Char('n') | KeyCode::Right => {
+                self.mode = Mode::Paused;
+                self.message.clear();
+                self.stepper.step();
+
+                if self.stepper.finished {
+                    self.stopped(Stop::Finished);
+                }
+            }
+            KeyCode::Char('p') => {
+                self.mode = match self.mode {
+                    Mode::Playing => Mode::Paused,
+                    _ => Mode::Playing,
+                };
+                self.message.clear();
+            }
+            KeyCode::Char('r') => {
+                self.mode = Mode::Running;
+                self.message = "Running, press any key to pause.".to_string();
+            }
+            KeyCode::Char('+') | KeyCode::Char('=') => {
+                self.delay = (self.delay / 2).max(Duration::from_millis(1));
+            }
+            KeyCode::Char('-') => self.delay = (self.delay * 2).min(SLOWEST),
+            KeyCode::Char('b') => self.entering = Some(String::new()),
+            KeyCode::Char('c') => {
+                self.stepper.breakpoints.clear();
+                self.message = "Cleared the breakpoints.".to_string();
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(&self, frame: &mut Frame) {
+        let [header, body, footer] = Layout::vertical([
+            Constraint::Length(3),
+            Constraint::Min(1),
+            Constraint::Length(4),
+        ])
+        .areas(frame.area());
+
+        let mode = match self.mode {
+            Mode::Paused if self.stepper.finished => "finished".to_string(),
+            Mode::Paused => "paused".to_string(),
+            Mode::Playing => format!("playing, a step every {:.0?}", self.delay),
+            Mode::Running => "running".to_string(),
+        };
+
+        frame.render_widget(
+            Paragraph::new(format!(
+                "Step {}   At {}   ({mode})",
+                self.stepper.steps,
+                self.stepper.simulation.location()
+            ))
+            .block(Block::bordered().title(format!(" Day {} ", self.day))),
+            header,
+        );
+
+        let state = self
+            .stepper
+            .simulation
+            .state(body.height.saturating_sub(2) as usize);
+
+        frame.render_widget(
+            Paragraph::new(state.join("\n")).block(Block::bordered()),
+            body,
+        );
+
+        let breakpoints = self
+            .stepper
+            .breakpoints
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+
+        let prompt = match &self.entering {
+            Some(text) => format!("Break at a step number or a location: {text}_"),
+            None => self.message.clone(),
+        };
+
+        frame.render_widget(
+            Paragraph::new(format!("{prompt}\n{HELP}")).block(
+                Block::bordered().title(format!(" Breakpoints: {} ", breakpoints.join(", "))),
+            ),
+            footer,
+        );
+    }
+}
+
+fn run<S: Simulation>(terminal: &mut DefaultTerminal, app: &mut App<S>) -> io::Result<()> {
+    let mut last_step = Instant::now();
+
+    while !app.quit {
+        terminal.draw(|frame| app.draw(frame))?;
+
+        let wait = match app.mode {
+            // There's nothing to do until a key is pressed.
+            Mode::Paused => Duration::from_secs(60),
+            Mode::Playing => app.delay.saturating_sub(last_step.elapsed()),
+            Mode::Running => Duration::ZERO,
+        };
+
+        if event::poll(wait)? {
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press {
+                    app.handle_key(key.code);
+                }
+            }
+
+            continue;
+        }
+
+        match app.mode {
+            Mode::Paused => {}
+            Mode::Playing => {
+                last_step = Instant::now();
+
+                if let Some(stop) = app.stepper.step_checking() {
+                    app.stopped(stop);
+                }
+            }
+            Mode::Running => {
+                if let Some(stop) = app.stepper.run_until(Instant::now() + RUN_SLICE) {
+                    app.stopped(stop);
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Takes over the terminal to show `stepper` until the user quits.
+pub fn show<S: Simulation>(day: u8, stepper: Stepper<S>) -> io::Result<()> {
+    let mut app = App {
+        day,
+        stepper,
+        mode: Mode::Paused,
+        delay: Duration::from_millis(200),
+        message: String::new(),
+        entering: None,
+        quit: false,
+    };
+
+    let mut terminal = ratatui::init();
+    let result = run(&mut terminal, &mut app);
+    ratatui::restore();
+
+    result
+}
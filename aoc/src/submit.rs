@@ -0,0 +1,329 @@
+//! `aoc submit`, which posts a solver's answer to the site. Every submission is
+//! logged, so that we never send an answer the site has already turned down and
+//! can warn about one that's outside the bounds it has given us.
+
+use crate::config::Config;
+use crate::registry;
+use crate::run;
+use crate::site::{self, Site, Verdict};
+use aoc_common::{Answer, Part, Progress};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// When the answer was submitted, in seconds since the Unix epoch.
+    pub submitted_at: u64,
+}
+
+/// Why an answer shouldn't be submitted.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Refusal {
+    /// The site has already said this answer is wrong.
+    KnownWrong(Verdict),
+    /// The part has already been solved, with this answer.
+    Solved(String),
+    /// The site asked us to wait, and this much of the wait is left.
+    MustWait(Duration),
+}
+
+impl fmt::Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::KnownWrong(verdict) => {
+                write!(f, "this answer was already submitted, and {verdict}")
+            }
+            Self::Solved(answer) => {
+                write!(f, "this part is already solved, the answer was {answer}")
+            }
+            Self::MustWait(wait) => {
+                write!(f, "the site asked us to wait, {}s are left", wait.as_secs())
+            }
+        }
+    }
+}
+
+impl std::error::Error for Refusal {}
+
+fn unix_time(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .map_or(0, |since_epoch| since_epoch.as_secs())
+}
+
+#[derive(Debug)]
+pub struct SubmissionLog {
+    path: PathBuf,
+    submissions: Vec<Submission>,
+}
+
+impl SubmissionLog {
+    /// Where the log lives, next to the cached inputs.
+    pub fn default_path(config: &Config) -> PathBuf {
+        config
+            .cache_dir
+            .join(site::YEAR.to_string())
+            .join("submissions.json")
+    }
+
+    /// Loads the log from `path`. A log that doesn't exist yet is empty.
+    pub fn load(path: PathBuf) -> io::Result<Self> {
+        let submissions = match fs::read_to_string(&path) {
+            Ok(raw) => serde_json::from_str(&raw).map_err(io::Error::other)?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Vec::new(),
+            Err(e) => return Err(e),
+        };
+
+        Ok(Self { path, submissions })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn for_part(&self, day: u8, part: u8) -> impl Iterator<Item = &Submission> {
+        self.submissions
+            .iter()
+            .filter(move |submission| submission.day == day && submission.part == part)
+    }
+
+    /// Checks whether `answer` is worth submitting at `now`.
+    pub fn check(&self, day: u8, part: u8, answer: &str, now: SystemTime) -> Result<(), Refusal> {
+        let now = unix_time(now);
+
+        for submission in self.for_part(day, part) {
+            match submission.verdict {
+                Verdict::Correct => return Err(Refusal::Solved(submission.answer.clone())),
+                verdict if verdict.is_wrong() && submission.answer == answer => {
+                    return Err(Refusal::KnownWrong(verdict));
+                }
+                Verdict::Wait { seconds } => {
+                    let wait_over = submission.submitted_at + seconds;
+
+                    if wait_over > now {
+                        return Err(Refusal::MustWait(Duration::from_secs(wait_over - now)));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Warnings about `answer` being outside a bound the site gave us for an
+    /// earlier answer. Only numeric answers have bounds.
+    pub fn bound_warnings(&self, day: u8, part: u8, answer: &str) -> Vec<String> {
+        let Ok(answer) = answer.parse::<i128>() else {
+            return Vec::new();
+        };
+
+        let mut warnings = Vec::new();
+
+        for submission in self.for_part(day, part) {
+            let Ok(earlier) = submission.answer.parse::<i128>() else {
+                continue;
+            };
+
+            match submission.verdict {
+                Verdict::TooHigh if answer >= earlier => {
+                    warnings.push(format!("{earlier} was already too high"));
+                }
+                Verdict::TooLow if answer <= earlier => {
+                    warnings.push(format!("{earlier} was already too low"));
+                }
+                _ => {}
+            }
+        }
+
+        warnings
+    }
+
+    pub fn record(&mut self, submission: Submission) {
+        self.submissions.push(submission);
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let json = serde_json::to_string_pretty(&self.submissions).map_err(io::Error::other)?;
+
+        fs::write(&self.path, json + "\n")
+    }
+}
+
+pub fn submit(day: u8, part: u8, input: Option<PathBuf>) -> ExitCode {
+    let Some(solver) = Part::new(part).and_then(|part| registry::find(day, part)) else {
+        eprintln!("There is no solver for day {day} part {part}.");
+        return ExitCode::FAILURE;
+    };
+
+    let Ok(given_input) = run::read_given_input(input) else {
+        return ExitCode::FAILURE;
+    };
+
+    let input = given_input.as_deref().unwrap_or(solver.input);
+    let answer = match solver.run(input, &Progress::new()) {
+        Ok((answer, _)) => answer,
+        Err(e) => {
+            eprint!("{}", e.render(input));
+            return ExitCode::FAILURE;
+        }
+    };
+
+    println!("Day {day} Part {part}: {answer}");
+
+    let config = match Config::load() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("Failed to load the config: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let log_path = SubmissionLog::default_path(&config);
+    let mut log = match SubmissionLog::load(log_path.clone()) {
+        Ok(log) => log,
+        Err(e) => {
+            eprintln!(
+                "Failed to load the submission log from {}: {e}",
+                log_path.display()
+            );
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match submit_answer(&config, &mut log, day, solver.part, &answer) {
+        Ok(Verdict::Correct) => ExitCode::SUCCESS,
+        Ok(_) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("Failed to submit the answer: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Checks `answer` against the log, submits it and logs the verdict.
+fn submit_answer(
+    config: &Config,
+    log: &mut SubmissionLog,
+    day: u8,
+    part: Part,
+    answer: &Answer,
+) -> Result<Verdict, Box<dyn std::error::Error>> {
+    let answer_text = answer.to_string();
+    let now = SystemTime::now();
+
+    log.check(day, part.number(), &answer_text, now)?;
+
+    for warning in log.bound_warnings(day, part.number(), &answer_text) {
+        eprintln!("Warning: this is probably wrong, {warning}.");
+    }
+
+    let verdict = Site::new(config)?.submit(day, part, answer)?;
+
+    println!("The site says {verdict}.");
+
+    log.record(Submission {
+        day,
+        part: part.number(),
+        answer: answer_text,
+        verdict,
+        submitted_at: unix_time(now),
+    });
+
+    log.save().map_err(|e| {
+        format!(
+            "failed to save the submission log to {}: {e}",
+            log.path().display()
+        )
+    })?;
+
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::site::test::{mock_site, test_config};
+
+    fn submission(answer: &str, verdict: Verdict, submitted_at: u64) -> Submission {
+        Submission {
+            day: 5,
+            part: 2,
+            answer: answer.to_string(),
+            verdict,
+            submitted_at,
+        }
+    }
+
+    #[test]
+    fn test_check_log() {
+        let mut log = SubmissionLog {
+            path: PathBuf::new(),
+            submissions: Vec::new(),
+        };
+        log.record(submission("100", Verdict::TooHigh, 0));
+        log.record(submission("10", Verdict::TooLow, 60));
+        log.record(submission("50", Verdict::Wait { seconds: 30 }, 120));
+
+        let at = |seconds| UNIX_EPOCH + Duration::from_secs(seconds);
+
+        assert_eq!(
+            log.check(5, 2, "100", at(600)),
+            Err(Refusal::KnownWrong(Verdict::TooHigh))
+        );
+        assert_eq!(
+            log.check(5, 2, "50", at(140)),
+            Err(Refusal::MustWait(Duration::from_secs(10)))
+        );
+        assert_eq!(log.check(5, 2, "50", at(600)), Ok(()));
+        assert_eq!(log.check(5, 1, "100", at(600)), Ok(()));
+
+        assert_eq!(
+            log.bound_warnings(5, 2, "150"),
+            vec!["100 was already too high"]
+        );
+        assert_eq!(
+            log.bound_warnings(5, 2, "5"),
+            vec!["10 was already too low"]
+        );
+        assert!(log.bound_warnings(5, 2, "50").is_empty());
+
+        log.record(submission("42", Verdict::Correct, 700));
+
+        assert_eq!(
+            log.check(5, 2, "43", at(800)),
+            Err(Refusal::Solved("42".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_submit_is_logged() {
+        let base_url = mock_site(|_, _, _, _| {
+            (
+                200,
+                "<article><p>That's not the right answer; your answer is too low.</p></article>"
+                    .to_string(),
+            )
+        });
+        let config = test_config(base_url);
+        let mut log = SubmissionLog::load(SubmissionLog::default_path(&config)).unwrap();
+
+        let verdict = submit_answer(&config, &mut log, 1, Part::One, &Answer::Unsigned(7)).unwrap();
+        assert_eq!(verdict, Verdict::TooLow);
+
+        let mut log = SubmissionLog::load(SubmissionLog::default_path(&config)).unwrap();
+        assert!(submit_answer(&config, &mut log, 1, Part::One, &Answer::Unsigned(7)).is_err());
+    }
+}
@@ -0,0 +1,164 @@
+//! `aoc verify`, which checks every solver against the known answers, and
+//! `aoc accept`, which records new ones.
+
+use crate::answers::{self, AnswerBook, KnownAnswer};
+use crate::registry::{self, Outcome};
+use crate::run;
+use aoc_common::Progress;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+/// How a solver's answer compares to the known answer for its input.
+#[derive(Debug, PartialEq, Eq)]
+enum Status {
+    Matches,
+    Changed,
+    /// There's no known answer for this input yet, so the solver wasn't run.
+    Missing,
+    ParseFailed,
+    Panicked,
+}
+
+impl Status {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Matches => "ok",
+            Self::Changed => "changed",
+            Self::Missing => "missing",
+            Self::ParseFailed => "parse error",
+            Self::Panicked => "panicked",
+        }
+    }
+
+    fn is_failure(&self) -> bool {
+        !matches!(self, Self::Matches | Self::Missing)
+    }
+}
+
+fn load_book(path: PathBuf) -> Option<AnswerBook> {
+    match AnswerBook::load(path.clone()) {
+        Ok(book) => Some(book),
+        Err(e) => {
+            eprintln!(
+                "Failed to load the known answers from {}: {e}",
+                path.display()
+            );
+            None
+        }
+    }
+}
+
+pub fn verify(day: Option<u8>, answers_path: PathBuf) -> ExitCode {
+    let Some(book) = load_book(answers_path) else {
+        return ExitCode::FAILURE;
+    };
+
+    let solvers = registry::SOLVERS
+        .iter()
+        .filter(|solver| day.is_none_or(|day| solver.day == day));
+
+    let mut statuses = Vec::new();
+
+    println!("{:<5}{:<6}{:<13}Answer", "Day", "Part", "Status");
+
+    for solver in solvers {
+        let input_sha256 = answers::hash_input(solver.input);
+        let expected = book.get(solver.day, solver.part.number(), &input_sha256);
+
+        // We only run solvers that we have something to compare against.
+        let (status, detail) = match expected {
+            None => (Status::Missing, String::new()),
+            Some(expected) => match solver.run_catching_panics(solver.input, &Progress::new()) {
+                Outcome::Solved(answer, _) if answer.to_string() == expected => {
+                    (Status::Matches, answer.to_string())
+                }
+                Outcome::Solved(answer, _) => {
+                    (Status::Changed, format!("{answer} (expected {expected})"))
+                }
+                Outcome::ParseFailed(e) => (Status::ParseFailed, e.to_string()),
+                Outcome::Panicked(message) => (Status::Panicked, message),
+                Outcome::Cancelled(_) => unreachable!("verify runs the solvers without a deadline"),
+                Outcome::Unsolved => (Status::Changed, format!("unsolved (expected {expected})")),
+            },
+        };
+
+        println!(
+            "{:<5}{:<6}{:<13}{detail}",
+            solver.day,
+            solver.part,
+            status.name()
+        );
+
+        statuses.push(status);
+    }
+
+    let count = |name: &str| {
+        statuses
+            .iter()
+            .filter(|status| status.name() == name)
+            .count()
+    };
+
+    println!(
+        "\n{} ok, {} changed, {} missing, {} failed to run",
+        count("ok"),
+        count("changed"),
+        count("missing"),
+        count("parse error") + count("panicked")
+    );
+
+    match statuses.iter().any(Status::is_failure) {
+        true => ExitCode::FAILURE,
+        false => ExitCode::SUCCESS,
+    }
+}
+
+pub fn accept(
+    day: u8,
+    part: Option<u8>,
+    input: Option<PathBuf>,
+    answers_path: PathBuf,
+) -> ExitCode {
+    let Some(solvers) = run::select_solvers(day, part) else {
+        return ExitCode::FAILURE;
+    };
+
+    let Ok(given_input) = run::read_given_input(input) else {
+        return ExitCode::FAILURE;
+    };
+
+    let Some(mut book) = load_book(answers_path) else {
+        return ExitCode::FAILURE;
+    };
+
+    for solver in solvers {
+        let input = given_input.as_deref().unwrap_or(solver.input);
+
+        let answer = match solver.run(input, &Progress::new()) {
+            Ok((answer, _)) => answer,
+            Err(e) => {
+                eprint!("{}", e.render(input));
+                return ExitCode::FAILURE;
+            }
+        };
+
+        println!("Day {} Part {}: {answer}", solver.day, solver.part);
+
+        book.record(KnownAnswer {
+            day: solver.day,
+            part: solver.part.number(),
+            input_sha256: answers::hash_input(input),
+            answer: answer.to_string(),
+        });
+    }
+
+    if let Err(e) = book.save() {
+        eprintln!(
+            "Failed to save the known answers to {}: {e}",
+            book.path().display()
+        );
+        return ExitCode::FAILURE;
+    }
+
+    ExitCode::SUCCESS
+}
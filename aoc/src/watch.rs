@@ -0,0 +1,297 @@
+//! `aoc watch`, which rebuilds and re-runs a day whenever its source or input
+//! changes, and shows the new answers next to the last run's.
+//!
+//! The runner can't reload a day it was built with, so every run builds this
+//! binary again with cargo and runs the fresh copy in a child process. That
+//! also means a part that never finishes can be killed once it runs too long,
+//! see [`child`].
+
+use crate::child::{self, Limits, PartRun};
+use crate::registry::Solver;
+use crate::run;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitCode, Stdio};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+/// How often the watched files are checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(300);
+
+/// When each watched file was last modified.
+type Snapshot = BTreeMap<PathBuf, SystemTime>;
+
+/// Adds every file under `path` to `snapshot`. A path that doesn't exist is
+/// skipped, since an editor might be halfway through replacing it.
+fn add_to_snapshot(path: &Path, snapshot: &mut Snapshot) {
+    let Ok(metadata) = fs::metadata(path) else {
+        return;
+    };
+
+    if metadata.is_dir() {
+        let Ok(entries) = fs::read_dir(path) else {
+            return;
+        };
+
+        for entry in entries.flatten() {
+            add_to_snapshot(&entry.path(), snapshot);
+        }
+    } else if let Ok(modified) = metadata.modified() {
+        snapshot.insert(path.to_path_buf(), modified);
+    }
+}
+
+fn take_snapshot(paths: &[PathBuf]) -> Snapshot {
+    let mut snapshot = Snapshot::new();
+
+    for path in paths {
+        add_to_snapshot(path, &mut snapshot);
+    }
+
+    snapshot
+}
+
+/// The files that were added, removed or modified between two snapshots.
+fn changed_files(before: &Snapshot, after: &Snapshot) -> Vec<PathBuf> {
+    let mut changed = after
+        .iter()
+        .filter(|(path, modified)| before.get(*path) != Some(modified))
+        .map(|(path, _)| path.clone())
+        .collect::<Vec<_>>();
+
+    changed.extend(
+        before
+            .keys()
+            .filter(|path| !after.contains_key(*path))
+            .cloned(),
+    );
+
+    changed
+}
+
+/// Builds the runner with cargo, showing cargo's output as it goes, and
+/// returns the path to the fresh binary. Returns `None` if the build failed.
+fn build_runner(release: bool) -> Option<PathBuf> {
+    let mut cargo = Command::new(std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into()));
+    cargo
+        .current_dir(crate::workspace_root())
+        .args([
+            "build",
+            "-p",
+            "aoc",
+            "--message-format=json-render-diagnostics",
+        ])
+        .stdout(Stdio::piped());
+
+    if release {
+        cargo.arg("--release");
+    }
+
+    let mut child = match cargo.spawn() {
+        Ok(child) => child,
+        Err(e) => {
+            eprintln!("Failed to start cargo: {e}");
+            return None;
+        }
+    };
+
+    let mut executable = None;
+
+    for line in BufReader::new(child.stdout.take().expect("stdout is piped"))
+        .lines()
+        .map_while(Result::ok)
+    {
+        if let Some(path) = runner_executable(&line) {
+            executable = Some(path);
+        }
+    }
+
+    match child.wait() {
+        Ok(status) if status.success() => executable,
+        _ => None,
+    }
+}
+
+/// The path to the runner binary, if `message` is cargo saying it built it.
+fn runner_executable(message: &str) -> Option<PathBuf> {
+    let message = serde_json::from_str::<serde_json::Value>(message).ok()?;
+
+    if message["reason"] != "compiler-artifact" || message["target"]["name"] != "aoc" {
+        return None;
+    }
+
+    message["executable"].as_str().map(PathBuf::from)
+}
+
+/// Prints this run's result for every part, with the previous run's beside it.
+fn print_runs(
+    solvers: &[&Solver],
+    runs: &[PartRun],
+    previous: Option<&[PartRun]>,
+    timeout: Duration,
+) {
+    println!(
+        "{:<6}{:<24}{:>12}  {:<24}{:>12}",
+        "Part", "Answer", "Time", "Previous", "Time"
+    );
+
+    for (i, (solver, run)) in solvers.iter().zip(runs).enumerate() {
+        let previous = previous.map(|previous| &previous[i]);
+        let marker = match previous {
+            Some(previous) if previous.answer() != run.answer() => "*",
+            _ => " ",
+        };
+
+        println!(
+            "{:<5}{marker}{:<24}{:>12}  {:<24}{:>12}",
+            solver.part,
+            run.answer(),
+            run.time(timeout),
+            previous.map_or(String::new(), PartRun::answer),
+            previous.map_or(String::new(), |previous| previous.time(timeout))
+        );
+    }
+}
+
+/// Blocks until one of `paths` changes, then waits for the changes to settle
+/// so that a save that touches several files only triggers one run.
+fn wait_for_change(paths: &[PathBuf], mut snapshot: Snapshot) -> (Vec<PathBuf>, Snapshot) {
+    let mut changed = Vec::new();
+
+    loop {
+        thread::sleep(POLL_INTERVAL);
+
+        let latest = take_snapshot(paths);
+        let new_changes = changed_files(&snapshot, &latest);
+
+        if new_changes.is_empty() && !changed.is_empty() {
+            return (changed, latest);
+        }
+
+        changed.extend(new_changes);
+        snapshot = latest;
+    }
+}
+
+pub fn watch(day: u8, part: Option<u8>, timeout: Duration, release: bool) -> ExitCode {
+    let Some(solvers) = run::select_solvers(day, part) else {
+        return ExitCode::FAILURE;
+    };
+
+    let root = crate::workspace_root();
+    let mut crate_names = solvers
+        .iter()
+        .map(|solver| solver.crate_name)
+        .collect::<Vec<_>>();
+    crate_names.dedup();
+
+    let paths = crate_names
+        .iter()
+        .flat_map(|name| {
+            [
+                root.join(name).join("src"),
+                root.join(name).join("input.txt"),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    println!(
+        "Watching {} for changes, press Ctrl-C to stop.",
+        crate_names.join(" and ")
+    );
+
+    let mut snapshot = take_snapshot(&paths);
+    let mut previous: Option<Vec<PartRun>> = None;
+    let limits = Limits {
+        timeout,
+        memory: None,
+    };
+
+    loop {
+        println!();
+
+        match build_runner(release) {
+            Some(runner) => {
+                let runs = solvers
+                    .iter()
+                    .map(|solver| child::run_part(&runner, solver, None, limits))
+                    .collect::<Vec<_>>();
+
+                print_runs(&solvers, &runs, previous.as_deref(), timeout);
+                previous = Some(runs);
+            }
+            None => println!("The build failed, waiting for the next change."),
+        }
+
+        let (changed, latest) = wait_for_change(&paths, snapshot);
+        snapshot = latest;
+
+        let changed = changed
+            .iter()
+            .map(|path| {
+                path.strip_prefix(&root)
+                    .unwrap_or(path)
+                    .display()
+                    .to_string()
+            })
+            .collect::<Vec<_>>();
+
+        println!("\nChanged: {}", changed.join(", "));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_changed_files() {
+        let dir = std::env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("src")).unwrap();
+        fs::write(dir.join("src/lib.rs"), "").unwrap();
+        fs::write(dir.join("input.txt"), "1").unwrap();
+
+        let paths = [dir.join("src"), dir.join("input.txt")];
+        let before = take_snapshot(&paths);
+        assert_eq!(before.len(), 2);
+
+        // Not every filesystem keeps times finer than a second.
+        let later = SystemTime::now() + Duration::from_secs(5);
+        fs::File::options()
+            .write(true)
+            .open(dir.join("input.txt"))
+            .unwrap()
+            .set_modified(later)
+            .unwrap();
+        fs::write(dir.join("src/main.rs"), "").unwrap();
+
+        let after = take_snapshot(&paths);
+        let mut changed = changed_files(&before, &after);
+        changed.sort();
+        assert_eq!(changed, [dir.join("input.txt"), dir.join("src/main.rs")]);
+
+        fs::remove_file(dir.join("src/main.rs")).unwrap();
+        assert_eq!(
+            changed_files(&after, &take_snapshot(&paths)),
+            [dir.join("src/main.rs")]
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_runner_executable() {
+        let built = r#"{"reason":"compiler-artifact","target":{"name":"aoc"},"executable":"/w/target/debug/aoc"}"#;
+        let other = r#"{"reason":"compiler-artifact","target":{"name":"day_9"},"executable":"/w/target/debug/day_9"}"#;
+
+        assert_eq!(
+            runner_executable(built),
+            Some(PathBuf::from("/w/target/debug/aoc"))
+        );
+        assert_eq!(runner_executable(other), None);
+        assert_eq!(runner_executable("not json"), None);
+    }
+}
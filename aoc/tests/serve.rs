@@ -0,0 +1,116 @@
+//! Runs `aoc serve` and posts inputs to it. Parts are solved by the runner
+//! binary in child processes, so this can't be a unit test of `serve`.
+
+use std::io::{BufRead, BufReader};
+use std::process::{Child, ChildStdout, Command, Stdio};
+
+/// A running server, which is killed once it's dropped.
+struct Server {
+    child: Child,
+    /// Kept open so that the server can still log the requests it answers.
+    _stdout: BufReader<ChildStdout>,
+    address: String,
+}
+
+impl Server {
+    fn start() -> Self {
+        let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+            .args(["serve", "--port", "0", "--timeout", "1"])
+            .args(["--memory-limit", "512", "--jobs", "2"])
+            .stdout(Stdio::piped())
+            .spawn()
+            .unwrap();
+
+        // The server says where it's listening before it takes any requests.
+        let mut stdout = BufReader::new(child.stdout.take().unwrap());
+        let mut line = String::new();
+        stdout.read_line(&mut line).unwrap();
+
+        let address = line
+            .split_whitespace()
+            .find_map(|word| word.strip_suffix(','))
+            .unwrap()
+            .to_string();
+
+        Self {
+            child,
+            _stdout: stdout,
+            address,
+        }
+    }
+
+    fn post(&self, path: &str, body: &str) -> (u16, serde_json::Value) {
+        let mut response = ureq::post(&format!("{}{path}", self.address))
+            .config()
+            .http_status_as_error(false)
+            .build()
+            .send(body)
+            .unwrap();
+
+        assert_eq!(
+            response.headers().get("Content-Type").unwrap(),
+            "application/json"
+        );
+
+        let reply = response.body_mut().read_to_string().unwrap();
+
+        (
+            response.status().as_u16(),
+            serde_json::from_str(&reply).unwrap(),
+        )
+    }
+}
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+#[test]
+fn test_serve() {
+    let server = Server::start();
+
+    let (status, reply) = server.post("/day/6/part/1", "Time: 7 15 30\nDistance: 9 40 200\n");
+    assert_eq!(status, 200);
+    assert_eq!(reply["answer"], "288");
+    assert_eq!(reply["answer_type"], "unsigned");
+    assert!(reply["solve_ns"].is_u64());
+    assert_eq!(reply["warnings"], serde_json::json!([]));
+
+    let (status, reply) = server.post(
+        "/day/6/part/2",
+        "\u{feff}Time: 7 15 30 \r\nDistance: 9 40 200\r\n",
+    );
+    assert_eq!((status, &reply["answer"]), (200, &"71503".into()));
+    assert_eq!(reply["warnings"].as_array().unwrap().len(), 3);
+
+    let (status, reply) = server.post("/day/4/part/1", "Card 1 1 2\n");
+    assert_eq!((status, &reply["error"]), (422, &"parse".into()));
+}
+
+#[test]
+fn test_serve_oversized_range() {
+    let server = Server::start();
+
+    // Part 2 would have to take a trillion seeds through the map.
+    let (status, reply) = server.post(
+        "/day/5/part/2",
+        "seeds: 0 1099511627776\n\nseed-to-soil map:\n50 98 2\n",
+    );
+    assert_eq!((status, &reply["error"]), (504, &"timed out".into()));
+
+    // The part was stopped in its own process, and the server carries on.
+    let (status, reply) = server.post("/day/6/part/1", "Time: 7\nDistance: 9\n");
+    assert_eq!((status, &reply["answer"]), (200, &"4".into()));
+}
+
+#[test]
+fn test_serve_times_out() {
+    let server = Server::start();
+
+    // Day 9 part 1 never finishes, but it stops once its timeout has passed.
+    let (status, reply) = server.post("/day/9/part/1", "0 3 6 9 12 15\n");
+    assert_eq!((status, &reply["error"]), (504, &"timed out".into()));
+}
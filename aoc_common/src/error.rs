@@ -0,0 +1,150 @@
+//! Errors for malformed puzzle input. Every parser reports where in the input it
+//! gave up, so the runner can point at the offending text instead of panicking.
+
+use std::fmt;
+
+/// An error from parsing a day's puzzle input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    /// The line the error is on, starting from 1.
+    pub line: usize,
+    /// The column the offending text starts at, counted in characters
+    /// and starting from 1.
+    pub column: usize,
+    /// The offending text. This is empty when something was missing, in which
+    /// case `column` points at where it should have been.
+    pub text: String,
+    /// What the parser expected to find instead.
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn new(
+        day: u8,
+        line: usize,
+        column: usize,
+        text: impl Into<String>,
+        expected: impl Into<String>,
+    ) -> Self {
+        Self {
+            day,
+            line,
+            column,
+            text: text.into(),
+            expected: expected.into(),
+        }
+    }
+
+    /// An error for something missing at the very end of `input`.
+    pub fn at_end(day: u8, input: &str, expected: impl Into<String>) -> Self {
+        let line = input.lines().count() + 1;
+
+        Self::new(day, line, 1, "", expected)
+    }
+
+    /// Renders the error as a diagnostic that quotes the line from `input`
+    /// and underlines the offending text.
+    pub fn render(&self, input: &str) -> String {
+        let mut rendered = format!("error: {self}\n");
+
+        if let Some(source_line) = input.lines().nth(self.line - 1) {
+            let gutter = " ".repeat(self.line.to_string().len());
+            let underline = "^".repeat(self.text.chars().count().max(1));
+            let padding = " ".repeat(self.column - 1);
+
+            rendered.push_str(&format!("{gutter} |\n"));
+            rendered.push_str(&format!("{} | {source_line}\n", self.line));
+            rendered.push_str(&format!("{gutter} | {padding}{underline}\n"));
+        }
+
+        rendered
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day {} input, line {}, column {}: expected {}",
+            self.day, self.line, self.column, self.expected
+        )?;
+
+        match self.text.is_empty() {
+            true => write!(f, ", found nothing"),
+            false => write!(f, ", found `{}`", self.text),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A single line of puzzle input, along with where it is. Parsers hand slices of
+/// `text` to [`Line::error`], which works out the column from the slice.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    pub day: u8,
+    /// The line number, starting from 1.
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// Splits `input` into numbered lines.
+    pub fn all(day: u8, input: &'a str) -> impl Iterator<Item = Line<'a>> {
+        input.lines().enumerate().map(move |(i, text)| Line {
+            day,
+            number: i + 1,
+            text,
+        })
+    }
+
+    /// Creates an error about `at`, which should be a slice of this line's `text`.
+    /// An empty slice at the end of the line points at something that's missing.
+    pub fn error(&self, at: &str, expected: impl Into<String>) -> ParseError {
+        ParseError::new(self.day, self.number, self.column_of(at), at, expected)
+    }
+
+    /// Finds which column `at` starts at. Slices that didn't come from this
+    /// line are reported at the start of it.
+    fn column_of(&self, at: &str) -> usize {
+        let start = self.text.as_ptr() as usize;
+        let position = at.as_ptr() as usize;
+
+        match position.checked_sub(start) {
+            Some(offset) if offset + at.len() <= self.text.len() => {
+                self.text[..offset].chars().count() + 1
+            }
+            _ => 1,
+        }
+    }
+
+    /// The empty slice at the end of the line, for errors about something
+    /// that should have come after everything else.
+    pub fn end(&self) -> &'a str {
+        &self.text[self.text.len()..]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let input = "Game 1: 3 blue, 4 red\nGame 2: 1 purple";
+        let line = Line::all(2, input).nth(1).unwrap();
+
+        let error = line.error(&line.text[10..], "`red`, `green` or `blue`");
+
+        assert_eq!(error.column, 11);
+        assert_eq!(
+            error.render(input),
+            "error: day 2 input, line 2, column 11: expected `red`, `green` or `blue`, found `purple`
+  |
+2 | Game 2: 1 purple
+  |           ^^^^^^
+"
+        );
+    }
+}
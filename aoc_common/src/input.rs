@@ -0,0 +1,152 @@
+//! Loading puzzle input at runtime. Every day still embeds its own `input.txt`,
+//! but that is only used when nothing else was given to the binary.
+
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::PathBuf;
+
+/// Where the puzzle input for a run should be read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// A file given with `--input <path>`.
+    File(PathBuf),
+    /// Standard input, asked for with `--input -`.
+    Stdin,
+    /// The `input.txt` that was compiled into the binary.
+    Embedded,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ArgsError {
+    MissingValue(String),
+    UnknownArgument(String),
+}
+
+impl fmt::Display for ArgsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingValue(flag) => write!(f, "`{flag}` expects a value"),
+            Self::UnknownArgument(argument) => write!(f, "unknown argument `{argument}`"),
+        }
+    }
+}
+
+impl std::error::Error for ArgsError {}
+
+impl InputSource {
+    /// Picks the input source from the arguments given to a day binary (without
+    /// the program name). Stdin has to be asked for with `--input -`, as guessing
+    /// from whether it's a terminal hangs forever in CI where stdin is an open pipe.
+    pub fn from_args<I>(args: I) -> Result<Self, ArgsError>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut args = args.into_iter();
+        let mut source = None;
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--input" | "-i" => {
+                    let value = args.next().ok_or(ArgsError::MissingValue(arg))?;
+
+                    source = Some(match value.as_str() {
+                        "-" => Self::Stdin,
+                        _ => Self::File(PathBuf::from(value)),
+                    });
+                }
+                _ => return Err(ArgsError::UnknownArgument(arg)),
+            }
+        }
+
+        Ok(source.unwrap_or(Self::Embedded))
+    }
+
+    /// Reads the input from this source. Only [`Self::Embedded`] reads
+    /// `embedded`, so stdin that turns out to be empty (like a broken pipe)
+    /// is an error rather than a quiet run on the wrong input.
+    pub fn read(&self, embedded: &str) -> io::Result<String> {
+        match self {
+            Self::File(path) => fs::read_to_string(path),
+            Self::Stdin => read_piped(io::stdin()),
+            Self::Embedded => Ok(embedded.to_string()),
+        }
+    }
+}
+
+/// Reads all of a piped input, which has to have something in it.
+fn read_piped(mut reader: impl Read) -> io::Result<String> {
+    let mut buffer = String::new();
+    reader.read_to_string(&mut buffer)?;
+
+    match buffer.is_empty() {
+        true => Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            "stdin was empty",
+        )),
+        false => Ok(buffer),
+    }
+}
+
+/// Loads the puzzle input for a day binary from its command line arguments,
+/// falling back to `embedded`. Exits the process with a message if the
+/// arguments are wrong or the input can't be read.
+pub fn load(embedded: &str) -> String {
+    let program = std::env::args().next().unwrap_or_default();
+
+    let source = match InputSource::from_args(std::env::args().skip(1)) {
+        Ok(source) => source,
+        Err(e) => {
+            eprintln!("{e}");
+            eprintln!("Usage: {program} [--input <path> | --input -]");
+            std::process::exit(2);
+        }
+    };
+
+    match source.read(embedded) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Failed to read the puzzle input from {source:?}: {e}");
+            std::process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn args(raw: &[&str]) -> Vec<String> {
+        raw.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn test_input_flag() {
+        assert_eq!(
+            InputSource::from_args(args(&["--input", "day_5/input.txt"])),
+            Ok(InputSource::File(PathBuf::from("day_5/input.txt")))
+        );
+        assert_eq!(
+            InputSource::from_args(args(&["-i", "-"])),
+            Ok(InputSource::Stdin)
+        );
+        assert_eq!(InputSource::from_args(args(&[])), Ok(InputSource::Embedded));
+        assert_eq!(
+            InputSource::from_args(args(&["--input"])),
+            Err(ArgsError::MissingValue("--input".to_string()))
+        );
+        assert_eq!(
+            InputSource::from_args(args(&["--part", "2"])),
+            Err(ArgsError::UnknownArgument("--part".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_read_piped() {
+        assert_eq!(read_piped(&b"1abc2\n"[..]).unwrap(), "1abc2\n");
+
+        let error = read_piped(&b""[..]).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::UnexpectedEof);
+        assert_eq!(error.to_string(), "stdin was empty");
+    }
+}
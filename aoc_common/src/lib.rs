@@ -0,0 +1,12 @@
+//! Shared pieces used by every day crate in the workspace.
+
+pub mod error;
+pub mod input;
+pub mod memory;
+pub mod normalise;
+pub mod progress;
+pub mod solution;
+
+pub use error::{Line, ParseError};
+pub use progress::{Cancelled, Progress};
+pub use solution::{Answer, Part, Solution};
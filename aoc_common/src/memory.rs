@@ -0,0 +1,163 @@
+//! Counting the heap memory a solver uses. [`CountingAllocator`] hands every
+//! allocation on to the system allocator and keeps totals as it goes, and a
+//! [`Phase`] reads those totals before and after a piece of work.
+//!
+//! Nothing is counted unless a binary installs the allocator with
+//! `#[global_allocator]`, which the runner does behind its
+//! `count-allocations` feature. The totals cover every thread, so a phase is
+//! only measured properly when nothing else is allocating at the same time.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// The bytes allocated and not yet freed.
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+/// The most `CURRENT` has been since the last [`Phase::start`].
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+
+/// A global allocator that counts what it allocates, see the [module
+/// docs](self).
+pub struct CountingAllocator;
+
+impl CountingAllocator {
+    fn allocated(size: usize) {
+        let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK.fetch_max(current, Ordering::Relaxed);
+
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED.fetch_add(size, Ordering::Relaxed);
+    }
+}
+
+// SAFETY: every call is passed straight on to `System`, which upholds the
+// contract, and the counting around it never touches the memory.
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+
+        if !ptr.is_null() {
+            Self::allocated(layout.size());
+        }
+
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+
+        if !ptr.is_null() {
+            Self::allocated(layout.size());
+        }
+
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    /// A reallocation counts as freeing the old block and allocating the new
+    /// one, since that's what it costs when it can't grow in place.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+
+        if !new_ptr.is_null() {
+            CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+            Self::allocated(new_size);
+        }
+
+        new_ptr
+    }
+}
+
+/// Whether [`CountingAllocator`] is the global allocator. Every program
+/// allocates before `main` runs, so if it had been installed it would have
+/// counted something by now.
+pub fn is_counting() -> bool {
+    ALLOCATIONS.load(Ordering::Relaxed) > 0
+}
+
+/// The bytes allocated and not yet freed, or zero if allocations aren't
+/// being counted.
+pub fn in_use() -> usize {
+    CURRENT.load(Ordering::Relaxed)
+}
+
+/// How much a phase of a run allocated.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    /// The most heap memory in use at once during the phase, on top of what
+    /// was already in use when it started.
+    pub peak: usize,
+    /// How many allocations were made, counting reallocations.
+    pub allocations: usize,
+    /// The total size of every allocation, whether or not it was freed.
+    pub bytes: usize,
+}
+
+/// The totals at the start of a phase, to take away from the totals at its
+/// end.
+#[derive(Debug)]
+pub struct Phase {
+    current: usize,
+    allocations: usize,
+    bytes: usize,
+}
+
+impl Phase {
+    /// Starts counting a phase, or returns `None` if allocations aren't being
+    /// counted.
+    pub fn start() -> Option<Self> {
+        if !is_counting() {
+            return None;
+        }
+
+        let current = CURRENT.load(Ordering::Relaxed);
+        PEAK.store(current, Ordering::Relaxed);
+
+        Some(Self {
+            current,
+            allocations: ALLOCATIONS.load(Ordering::Relaxed),
+            bytes: ALLOCATED.load(Ordering::Relaxed),
+        })
+    }
+
+    pub fn finish(self) -> Stats {
+        Stats {
+            peak: PEAK.load(Ordering::Relaxed).saturating_sub(self.current),
+            allocations: ALLOCATIONS.load(Ordering::Relaxed) - self.allocations,
+            bytes: ALLOCATED.load(Ordering::Relaxed) - self.bytes,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::hint::black_box;
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    #[test]
+    fn test_phase() {
+        assert!(is_counting());
+
+        // Other tests allocate on their own threads at the same time, so we
+        // can only check that at least this phase's memory was counted.
+        let phase = Phase::start().unwrap();
+
+        let mut numbers = black_box(Vec::<u8>::with_capacity(1 << 20));
+        numbers.extend(std::iter::repeat_n(1, 1 << 20));
+        drop(black_box(numbers));
+
+        let stats = phase.finish();
+
+        assert!(stats.peak >= 1 << 20, "{stats:?}");
+        assert!(stats.allocations >= 1, "{stats:?}");
+        assert!(stats.bytes >= 1 << 20, "{stats:?}");
+    }
+}
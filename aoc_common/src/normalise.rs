@@ -0,0 +1,177 @@
+//! Tidying up the whitespace quirks an input picks up on its way to us, before
+//! a day parses it. Saving on Windows adds a `\r` to every line and sometimes a
+//! byte order mark, and copying from a browser can leave spaces at the ends of
+//! lines. The parsers are written for the input as the site serves it, so
+//! [`normalise`] takes those back out and says what it changed.
+
+use std::borrow::Cow;
+use std::fmt;
+
+const BYTE_ORDER_MARK: char = '\u{feff}';
+
+/// Something [`normalise`] changed about an input, or noticed and left alone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Quirk {
+    /// The input started with a byte order mark, which was removed.
+    ByteOrderMark,
+    /// This many lines ended in `\r\n`, which is now just `\n`.
+    LineEndings(usize),
+    /// This many lines had whitespace at the end, which was removed.
+    TrailingWhitespace(usize),
+    /// The input ends in this many blank lines. These are left in, as a blank
+    /// line means something to some days, but they're likely a mistake.
+    TrailingBlankLines(usize),
+}
+
+impl fmt::Display for Quirk {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let lines = |count: usize| match count {
+            1 => "1 line".to_string(),
+            _ => format!("{count} lines"),
+        };
+
+        match self {
+            Self::ByteOrderMark => write!(f, "removed a byte order mark from the start"),
+            Self::LineEndings(count) => {
+                write!(f, "changed the \\r\\n line endings of {}", lines(*count))
+            }
+            Self::TrailingWhitespace(count) => {
+                write!(f, "trimmed whitespace from the end of {}", lines(*count))
+            }
+            Self::TrailingBlankLines(count) => write!(
+                f,
+                "the input ends in {} blank {}",
+                count,
+                match count {
+                    1 => "line",
+                    _ => "lines",
+                }
+            ),
+        }
+    }
+}
+
+/// An input after [`normalise`], along with what was done to it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Normalised<'a> {
+    /// The input, only copied if something had to change.
+    pub text: Cow<'a, str>,
+    pub quirks: Vec<Quirk>,
+}
+
+/// Removes a byte order mark, turns `\r\n` into `\n` and trims whitespace from
+/// the end of every line. Blank lines at the end are only reported.
+pub fn normalise(input: &str) -> Normalised<'_> {
+    let mut quirks = Vec::new();
+
+    let without_mark = match input.strip_prefix(BYTE_ORDER_MARK) {
+        Some(rest) => {
+            quirks.push(Quirk::ByteOrderMark);
+            rest
+        }
+        None => input,
+    };
+
+    let mut text = String::with_capacity(without_mark.len());
+    let mut line_endings = 0;
+    let mut trailing_whitespace = 0;
+
+    for line in without_mark.split_inclusive('\n') {
+        let (content, newline) = match line.strip_suffix('\n') {
+            Some(content) => (content, true),
+            None => (line, false),
+        };
+
+        let content = match content.strip_suffix('\r') {
+            Some(content) if newline => {
+                line_endings += 1;
+                content
+            }
+            _ => content,
+        };
+
+        let trimmed = content.trim_end();
+
+        if trimmed.len() != content.len() {
+            trailing_whitespace += 1;
+        }
+
+        text.push_str(trimmed);
+
+        if newline {
+            text.push('\n');
+        }
+    }
+
+    if line_endings > 0 {
+        quirks.push(Quirk::LineEndings(line_endings));
+    }
+
+    if trailing_whitespace > 0 {
+        quirks.push(Quirk::TrailingWhitespace(trailing_whitespace));
+    }
+
+    let blank_lines = text
+        .lines()
+        .rev()
+        .take_while(|line| line.is_empty())
+        .count();
+
+    if blank_lines > 0 {
+        quirks.push(Quirk::TrailingBlankLines(blank_lines));
+    }
+
+    let changed = quirks
+        .iter()
+        .any(|quirk| !matches!(quirk, Quirk::TrailingBlankLines(_)));
+
+    Normalised {
+        text: match changed {
+            true => Cow::Owned(text),
+            false => Cow::Borrowed(input),
+        },
+        quirks,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_normalise() {
+        let normalised = normalise("\u{feff}seeds: 1 2\r\n\r\n  \r\nsoil map: \t\r\n1 2 3");
+
+        assert_eq!(normalised.text, "seeds: 1 2\n\n\nsoil map:\n1 2 3");
+        assert_eq!(
+            normalised.quirks,
+            [
+                Quirk::ByteOrderMark,
+                Quirk::LineEndings(4),
+                Quirk::TrailingWhitespace(2)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_trailing_blank_lines() {
+        let normalised = normalise("Game 1: 3 blue\n\n\n");
+
+        assert!(matches!(normalised.text, Cow::Borrowed(_)));
+        assert_eq!(normalised.quirks, [Quirk::TrailingBlankLines(2)]);
+        assert_eq!(
+            normalised.quirks[0].to_string(),
+            "the input ends in 2 blank lines"
+        );
+    }
+
+    #[test]
+    fn test_clean_input() {
+        let input = "467..114..\n...*......\n";
+        let normalised = normalise(input);
+
+        assert!(matches!(normalised.text, Cow::Borrowed(_)));
+        assert_eq!(normalised.text, input);
+        assert!(normalised.quirks.is_empty());
+    }
+}
@@ -0,0 +1,208 @@
+//! Letting a solver that can run for a long time say how far it's got, and
+//! stopping it once it's taken too long. Such a solver is handed a
+//! [`Progress`], reports the work it's done with [`Progress::advance`] and
+//! gives up with [`Cancelled`] as soon as that says to.
+//!
+//! Nothing is stopped from the outside, the solver has to check in, so a
+//! check only goes in loops that might not finish soon, often enough to stop
+//! promptly but not so often that the checks slow it down.
+
+use std::error::Error;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Asks the solvers holding a copy of it to stop.
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Why a solver stopped before it had an answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cancelled {
+    /// It was still going when its deadline passed.
+    TimedOut,
+    /// Its [`CancelToken`] was cancelled.
+    Stopped,
+}
+
+impl fmt::Display for Cancelled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TimedOut => write!(f, "the solver was still going at its deadline"),
+            Self::Stopped => write!(f, "the solver was stopped"),
+        }
+    }
+}
+
+impl Error for Cancelled {}
+
+/// How far a solver has got.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Update {
+    pub done: u64,
+    /// How much there is to do in all, if the solver knows.
+    pub total: Option<u64>,
+}
+
+/// What a solver checks in with while it works, see the [module
+/// docs](self).
+#[derive(Default)]
+pub struct Progress {
+    token: CancelToken,
+    deadline: Option<Instant>,
+    done: AtomicU64,
+    /// Zero until the solver says how much there is to do.
+    total: AtomicU64,
+    on_update: Option<Box<dyn Fn(Update) + Send + Sync>>,
+}
+
+impl Progress {
+    /// A progress that's never cancelled and doesn't tell anyone anything.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stops the solver once `token` is cancelled.
+    pub fn with_token(mut self, token: CancelToken) -> Self {
+        self.token = token;
+        self
+    }
+
+    /// Stops the solver if it's still going `timeout` from now.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.deadline = Some(Instant::now() + timeout);
+        self
+    }
+
+    /// Calls `on_update` whenever the solver reports. It can be called from
+    /// any of the solver's threads, so it should be quick.
+    pub fn on_update(mut self, on_update: impl Fn(Update) + Send + Sync + 'static) -> Self {
+        self.on_update = Some(Box::new(on_update));
+        self
+    }
+
+    /// How far the solver has got so far.
+    pub fn update(&self) -> Update {
+        Update {
+            done: self.done.load(Ordering::Relaxed),
+            total: match self.total.load(Ordering::Relaxed) {
+                0 => None,
+                total => Some(total),
+            },
+        }
+    }
+
+    fn notify(&self) {
+        if let Some(on_update) = &self.on_update {
+            on_update(self.update());
+        }
+    }
+
+    /// Starts counting again from nothing done, out of `total` if the solver
+    /// knows how much there is to do.
+    pub fn start(&self, total: Option<u64>) {
+        self.done.store(0, Ordering::Relaxed);
+        self.total
+            .store(total.unwrap_or_default(), Ordering::Relaxed);
+        self.notify();
+    }
+
+    /// Adds `amount` to the work done, then checks whether the solver should
+    /// stop.
+    pub fn advance(&self, amount: u64) -> Result<(), Cancelled> {
+        self.done.fetch_add(amount, Ordering::Relaxed);
+        self.notify();
+        self.check()
+    }
+
+    /// Returns an error if the solver should stop.
+    pub fn check(&self) -> Result<(), Cancelled> {
+        if self.token.is_cancelled() {
+            return Err(Cancelled::Stopped);
+        }
+
+        match self.deadline {
+            Some(deadline) if Instant::now() >= deadline => Err(Cancelled::TimedOut),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Runs `solve` with a [`Progress`] that's never cancelled, for calling a
+/// solver that checks in from somewhere that doesn't care to.
+pub fn uncancellable<T>(solve: impl FnOnce(&Progress) -> Result<T, Cancelled>) -> T {
+    solve(&Progress::new()).expect("A progress without a token or a deadline is never cancelled.")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[test]
+    fn test_updates() {
+        let updates = Arc::new(Mutex::new(Vec::new()));
+        let progress = Progress::new().on_update({
+            let updates = Arc::clone(&updates);
+            move |update| updates.lock().unwrap().push(update)
+        });
+
+        progress.start(Some(10));
+        progress.advance(4).unwrap();
+        progress.start(None);
+        progress.advance(1).unwrap();
+
+        assert_eq!(
+            *updates.lock().unwrap(),
+            [
+                Update {
+                    done: 0,
+                    total: Some(10)
+                },
+                Update {
+                    done: 4,
+                    total: Some(10)
+                },
+                Update {
+                    done: 0,
+                    total: None
+                },
+                Update {
+                    done: 1,
+                    total: None
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cancelling() {
+        let token = CancelToken::new();
+        let progress = Progress::new().with_token(token.clone());
+
+        assert_eq!(progress.check(), Ok(()));
+        token.cancel();
+        assert_eq!(progress.advance(1), Err(Cancelled::Stopped));
+
+        let progress = Progress::new().with_timeout(Duration::ZERO);
+        assert_eq!(progress.check(), Err(Cancelled::TimedOut));
+
+        let done = uncancellable(|progress| progress.advance(2).map(|()| progress.update().done));
+        assert_eq!(done, 2);
+    }
+}
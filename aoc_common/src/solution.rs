@@ -0,0 +1,311 @@
+//! The [`Solution`] trait every day implements, so that the runner, tests and
+//! benchmarks can call into any day the same way.
+
+use crate::error::ParseError;
+use crate::memory::{self, Phase};
+use crate::normalise::normalise;
+use crate::progress::{Cancelled, Progress};
+use std::error::Error;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+/// One of the two parts of a day's puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn new(number: u8) -> Option<Self> {
+        match number {
+            1 => Some(Self::One),
+            2 => Some(Self::Two),
+            _ => None,
+        }
+    }
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Self::One => 1,
+            Self::Two => 2,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // We go through the number's own `Display` so that padding still works.
+        fmt::Display::fmt(&self.number(), f)
+    }
+}
+
+/// The answer to one part of a puzzle. Most answers are numbers, but we keep
+/// track of whether they can be negative so that they print the same way the
+/// solver computed them.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Unsigned(u64),
+    Signed(i64),
+    Text(String),
+}
+
+impl Answer {
+    /// A name for the kind of answer this is, for machine-readable output.
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Self::Unsigned(_) => "unsigned",
+            Self::Signed(_) => "signed",
+            Self::Text(_) => "text",
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unsigned(answer) => write!(f, "{answer}"),
+            Self::Signed(answer) => write!(f, "{answer}"),
+            Self::Text(answer) => write!(f, "{answer}"),
+        }
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(answer: u32) -> Self {
+        Self::Unsigned(answer as u64)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(answer: u64) -> Self {
+        Self::Unsigned(answer)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(answer: usize) -> Self {
+        Self::Unsigned(answer as u64)
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(answer: i64) -> Self {
+        Self::Signed(answer)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(answer: String) -> Self {
+        Self::Text(answer)
+    }
+}
+
+/// A solution to a day's puzzle. Parsing is kept apart from solving so that
+/// both can be timed on their own, and so both parts can share one parse.
+///
+/// Some days keep their second part in a separate crate (like `day_1_p_2`), so
+/// a part that a crate doesn't solve returns `None`.
+pub trait Solution {
+    /// The puzzle input after parsing.
+    type Input;
+
+    /// The day of December this puzzle was released on.
+    const DAY: u8;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part_one(_input: &Self::Input) -> Option<Answer> {
+        None
+    }
+
+    fn part_two(_input: &Self::Input) -> Option<Answer> {
+        None
+    }
+
+    /// Things about a parsed input that the puzzle promises won't happen, but
+    /// which the parts would quietly get wrong rather than fail on. Most days
+    /// trust their inputs, so by default there's nothing to say.
+    fn check(_input: &Self::Input) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Solves the given part.
+    fn solve(input: &Self::Input, part: Part) -> Option<Answer> {
+        match part {
+            Part::One => Self::part_one(input),
+            Part::Two => Self::part_two(input),
+        }
+    }
+
+    /// Solves the given part, reporting to `progress` as it goes and stopping
+    /// when it says to. Only parts that can take a long time check in, so by
+    /// default this is just [`Solution::solve`].
+    fn solve_with_progress(
+        input: &Self::Input,
+        part: Part,
+        _progress: &Progress,
+    ) -> Result<Option<Answer>, Cancelled> {
+        Ok(Self::solve(input, part))
+    }
+}
+
+/// Why [`run`] didn't come back with an answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RunError {
+    Parse(ParseError),
+    Cancelled(Cancelled),
+    /// The solution doesn't solve the part, or not yet.
+    Unsolved,
+}
+
+impl RunError {
+    /// Renders the error the way the runner prints it, quoting the line of
+    /// `input` a parse error is on.
+    pub fn render(&self, input: &str) -> String {
+        match self {
+            Self::Parse(e) => e.render(input),
+            Self::Cancelled(reason) => format!("error: {reason}\n"),
+            Self::Unsolved => format!("error: {self}\n"),
+        }
+    }
+}
+
+impl fmt::Display for RunError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse(e) => write!(f, "{e}"),
+            Self::Cancelled(reason) => write!(f, "{reason}"),
+            Self::Unsolved => write!(f, "the part hasn't been solved yet"),
+        }
+    }
+}
+
+impl Error for RunError {}
+
+impl From<ParseError> for RunError {
+    fn from(e: ParseError) -> Self {
+        Self::Parse(e)
+    }
+}
+
+/// How long a run of a solver took, split into its phases.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub solve: Duration,
+    /// What each phase allocated, if allocations are being counted. See
+    /// [`memory`].
+    pub memory: Option<Memory>,
+}
+
+/// What each phase of a run allocated.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Memory {
+    pub parse: memory::Stats,
+    pub solve: memory::Stats,
+    /// The bytes still allocated once the parsed input has been dropped,
+    /// apart from the answer's. Anything here was leaked, like nodes that
+    /// point at each other through an `Rc`.
+    pub leaked: usize,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.solve
+    }
+}
+
+/// Parses `input` and solves one part of it, timing both phases and counting
+/// what they allocate. The part can be stopped through `progress`, although
+/// parsing can't. Returns `Ok(None)` if the solution doesn't solve that part.
+pub fn run<S: Solution>(
+    input: &str,
+    part: Part,
+    progress: &Progress,
+) -> Result<Option<(Answer, Timings)>, RunError> {
+    let in_use_before = memory::in_use();
+
+    let parse_span = tracing::info_span!("parse", day = S::DAY, bytes = input.len()).entered();
+    let parse_phase = Phase::start();
+    let parse_start = Instant::now();
+    let parsed = parse::<S>(input)?;
+    let parse = parse_start.elapsed();
+    let parse_memory = parse_phase.map(Phase::finish);
+    tracing::debug!(?parse, "parsed the input");
+    drop(parse_span);
+
+    let solve_span = tracing::info_span!("solve", day = S::DAY, part = part.number()).entered();
+    let solve_phase = Phase::start();
+    let solve_start = Instant::now();
+    let answer = S::solve_with_progress(&parsed, part, progress).map_err(|reason| {
+        tracing::debug!(%reason, "stopped the part");
+        RunError::Cancelled(reason)
+    })?;
+    let solve = solve_start.elapsed();
+    let solve_memory = solve_phase.map(Phase::finish);
+    match &answer {
+        Some(answer) => tracing::debug!(%answer, ?solve, "solved the part"),
+        None => tracing::debug!("this solution doesn't solve the part"),
+    }
+    drop(solve_span);
+
+    // Whatever's left once the input is gone, and the answer's own memory is
+    // put aside, was never freed.
+    drop(parsed);
+    let answer_size = match &answer {
+        Some(Answer::Text(text)) => text.capacity(),
+        _ => 0,
+    };
+    let leaked = memory::in_use()
+        .saturating_sub(in_use_before)
+        .saturating_sub(answer_size);
+
+    let memory = parse_memory.zip(solve_memory).map(|(parse, solve)| Memory {
+        parse,
+        solve,
+        leaked,
+    });
+
+    Ok(answer.map(|answer| {
+        (
+            answer,
+            Timings {
+                parse,
+                solve,
+                memory,
+            },
+        )
+    }))
+}
+
+/// Normalises `input` (see [`normalise`]) and parses it. Anything normalising
+/// changed is reported as a warning.
+pub fn parse<S: Solution>(input: &str) -> Result<S::Input, ParseError> {
+    let normalised = normalise(input);
+
+    for quirk in &normalised.quirks {
+        tracing::warn!(day = S::DAY, "{quirk}");
+    }
+
+    S::parse(&normalised.text)
+}
+
+/// Parses `input` for a day binary, printing a diagnostic and exiting the
+/// process if it's malformed.
+pub fn parse_or_exit<S: Solution>(input: &str) -> S::Input {
+    // Day binaries don't print traces, so the quirks are printed here instead.
+    let normalised = normalise(input);
+
+    for quirk in &normalised.quirks {
+        eprintln!("Warning: {quirk}.");
+    }
+
+    match S::parse(&normalised.text) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            eprint!("{}", e.render(&normalised.text));
+            std::process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,171 @@
+//! The grid itself, stored row by row.
+
+use crate::pos::{Adjacency, Pos};
+use aoc_common::ParseError;
+use std::ops::{Index, IndexMut};
+
+/// A rectangle of cells, `width` wide, with `(0, 0)` in the top left corner.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Makes a grid out of `cells`, listed row by row from the top.
+    ///
+    /// Panics if `cells` doesn't fill a whole number of rows.
+    pub fn new(width: usize, cells: Vec<T>) -> Self {
+        let height = match width {
+            0 => 0,
+            _ => cells.len() / width,
+        };
+
+        assert_eq!(
+            width * height,
+            cells.len(),
+            "{} cells don't make rows {width} wide",
+            cells.len()
+        );
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Reads a map of characters, turning each one into a cell with `cell`.
+    /// Every row has to be as wide as the first.
+    pub fn from_chars(
+        day: u8,
+        input: &str,
+        cell: impl FnMut(char) -> T,
+    ) -> Result<Self, ParseError> {
+        let map = aoc_parse::char_grid(day, input)?;
+
+        Ok(Self::new(map.width, map.cells(cell)))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.x < self.width && pos.y < self.height
+    }
+
+    fn index_of(&self, pos: Pos) -> Option<usize> {
+        self.contains(pos).then(|| pos.y * self.width + pos.x)
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.index_of(pos).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.index_of(pos).map(|i| &mut self.cells[i])
+    }
+
+    /// The cells of row `y`, from the left.
+    ///
+    /// Panics if there's no row `y`.
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(y < self.height, "there's no row {y}");
+
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Every position in the grid, row by row from the top.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Pos::new(x, y)))
+    }
+
+    /// Every cell along with its position, row by row from the top.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The neighbours of `pos` that are inside the grid.
+    pub fn neighbours(&self, pos: Pos, adjacency: Adjacency) -> impl Iterator<Item = Pos> + '_ {
+        adjacency
+            .steps()
+            .iter()
+            .filter_map(move |&(dx, dy)| pos.step(dx, dy))
+            .filter(|neighbour| self.contains(*neighbour))
+    }
+
+    /// The up, down, left and right neighbours of `pos` inside the grid.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.neighbours(pos, Adjacency::Four)
+    }
+
+    /// All eight neighbours of `pos` inside the grid, diagonals included.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.neighbours(pos, Adjacency::Eight)
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{pos} is outside the grid"))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{pos} is outside the grid"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_grid() {
+        let mut grid = Grid::from_chars(3, "abc\ndef", |c| c).unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Pos::new(2, 0)], 'c');
+        assert_eq!(grid.get(Pos::new(0, 1)), Some(&'d'));
+        assert_eq!(grid.get(Pos::new(3, 0)), None);
+        assert_eq!(grid.get(Pos::new(0, 2)), None);
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+
+        grid[Pos::new(1, 1)] = 'E';
+        assert_eq!(grid.iter().map(|(_, c)| c).collect::<String>(), "abcdEf");
+
+        assert!(Grid::from_chars(3, "abc\nde", |c| c).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "don't make rows")]
+    fn test_ragged_cells() {
+        Grid::new(2, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(3, vec![0; 9]);
+
+        let corner = grid.neighbours4(Pos::new(0, 0)).collect::<Vec<_>>();
+        assert_eq!(corner, [Pos::new(1, 0), Pos::new(0, 1)]);
+
+        assert_eq!(grid.neighbours8(Pos::new(1, 1)).count(), 8);
+        assert_eq!(grid.neighbours8(Pos::new(2, 2)).count(), 3);
+        assert_eq!(grid.neighbours8(Pos::new(2, 1)).count(), 5);
+        assert_eq!(grid.neighbours4(Pos::new(1, 2)).count(), 3);
+    }
+}
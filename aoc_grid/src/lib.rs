@@ -0,0 +1,11 @@
+//! A 2D grid for puzzles whose input is a map, with typed positions so `x`
+//! and `y` can't be mixed up, bounds checked neighbours, and scans for runs
+//! of cells along a row and regions of touching cells.
+
+pub mod grid;
+pub mod pos;
+pub mod scan;
+
+pub use grid::Grid;
+pub use pos::{Adjacency, Pos};
+pub use scan::Run;
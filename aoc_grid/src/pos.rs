@@ -0,0 +1,76 @@
+//! Coordinates on a grid, and which cells count as next to each other.
+
+use std::fmt;
+
+/// A cell's position, `x` columns from the left and `y` rows from the top.
+/// Keeping both in one type means they can't be swapped by accident, which is
+/// easy to do with a bare `(row, col)` tuple.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Pos {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Pos {
+    pub const fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+
+    /// The position `dx` columns right and `dy` rows down, or `None` if that's
+    /// off the left or top edge.
+    pub fn step(self, dx: isize, dy: isize) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_add_signed(dx)?,
+            y: self.y.checked_add_signed(dy)?,
+        })
+    }
+}
+
+impl fmt::Display for Pos {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+/// Which cells around a cell are its neighbours.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Adjacency {
+    /// Up, down, left and right.
+    Four,
+    /// The four above along with the diagonals.
+    Eight,
+}
+
+impl Adjacency {
+    /// The `(dx, dy)` steps to every neighbour, clockwise from the one above.
+    pub fn steps(self) -> &'static [(isize, isize)] {
+        match self {
+            Self::Four => &[(0, -1), (1, 0), (0, 1), (-1, 0)],
+            Self::Eight => &[
+                (0, -1),
+                (1, -1),
+                (1, 0),
+                (1, 1),
+                (0, 1),
+                (-1, 1),
+                (-1, 0),
+                (-1, -1),
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_step() {
+        let pos = Pos::new(0, 2);
+
+        assert_eq!(pos.step(1, -1), Some(Pos::new(1, 1)));
+        assert_eq!(pos.step(-1, 0), None);
+        assert_eq!(pos.step(0, -3), None);
+        assert_eq!(pos.to_string(), "(0, 2)");
+    }
+}
@@ -0,0 +1,212 @@
+//! Finding groups of cells: runs along a row, and regions of touching cells.
+
+use crate::grid::Grid;
+use crate::pos::{Adjacency, Pos};
+use std::collections::VecDeque;
+use std::ops::Range;
+
+/// Cells next to each other on row `y`, from column `xs.start` up to but not
+/// including `xs.end`. A number in day 3's schematic is one of these.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Run {
+    pub y: usize,
+    pub xs: Range<usize>,
+}
+
+impl Run {
+    pub fn len(&self) -> usize {
+        self.xs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.xs.is_empty()
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.y == self.y && self.xs.contains(&pos.x)
+    }
+
+    /// The positions in the run, from the left.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> + '_ {
+        self.xs.clone().map(|x| Pos::new(x, self.y))
+    }
+}
+
+impl<T> Grid<T> {
+    /// Every longest run of cells that `matches`, row by row from the top.
+    pub fn runs(&self, mut matches: impl FnMut(&T) -> bool) -> Vec<Run> {
+        let mut runs = Vec::new();
+
+        for y in 0..self.height() {
+            let mut start = None;
+
+            for (x, cell) in self.row(y).iter().enumerate() {
+                match (start, matches(cell)) {
+                    (None, true) => start = Some(x),
+                    (Some(run_start), false) => {
+                        runs.push(Run {
+                            y,
+                            xs: run_start..x,
+                        });
+                        start = None;
+                    }
+                    _ => {}
+                }
+            }
+
+            if let Some(run_start) = start {
+                runs.push(Run {
+                    y,
+                    xs: run_start..self.width(),
+                });
+            }
+        }
+
+        runs
+    }
+
+    /// The cells inside the grid that touch `run`, diagonals included, row by
+    /// row from the top.
+    pub fn run_border(&self, run: &Run) -> Vec<Pos> {
+        if run.is_empty() || self.width() == 0 || self.height() == 0 {
+            return Vec::new();
+        }
+
+        let ys = run.y.saturating_sub(1)..=(run.y + 1).min(self.height() - 1);
+        let xs = run.xs.start.saturating_sub(1)..=run.xs.end.min(self.width() - 1);
+
+        ys.flat_map(|y| xs.clone().map(move |x| Pos::new(x, y)))
+            .filter(|pos| !run.contains(*pos))
+            .collect()
+    }
+
+    /// The region of cells that `matches` which can be reached from `start`
+    /// through neighbours that also match, nearest first. It's empty if
+    /// `start` doesn't match.
+    pub fn flood_fill(
+        &self,
+        start: Pos,
+        adjacency: Adjacency,
+        mut matches: impl FnMut(&T) -> bool,
+    ) -> Vec<Pos> {
+        let mut seen = vec![false; self.width() * self.height()];
+
+        self.fill(start, adjacency, &mut matches, &mut seen)
+    }
+
+    /// Every separate region of cells that `matches`, in the order their first
+    /// cell comes row by row.
+    pub fn regions(
+        &self,
+        adjacency: Adjacency,
+        mut matches: impl FnMut(&T) -> bool,
+    ) -> Vec<Vec<Pos>> {
+        let mut seen = vec![false; self.width() * self.height()];
+
+        self.positions()
+            .map(|pos| self.fill(pos, adjacency, &mut matches, &mut seen))
+            .filter(|region| !region.is_empty())
+            .collect()
+    }
+
+    /// Flood fills from `start`, skipping cells that are already `seen` by
+    /// an earlier fill.
+    fn fill(
+        &self,
+        start: Pos,
+        adjacency: Adjacency,
+        matches: &mut impl FnMut(&T) -> bool,
+        seen: &mut [bool],
+    ) -> Vec<Pos> {
+        let width = self.width();
+        let mut visit = |pos: Pos, seen: &mut [bool]| {
+            let i = pos.y * width + pos.x;
+            let fresh = self.get(pos).is_some_and(|cell| !seen[i] && matches(cell));
+
+            if fresh {
+                seen[i] = true;
+            }
+
+            fresh
+        };
+
+        let mut region = Vec::new();
+        let mut queue = VecDeque::new();
+
+        if visit(start, seen) {
+            queue.push_back(start);
+        }
+
+        while let Some(pos) = queue.pop_front() {
+            region.push(pos);
+
+            for neighbour in self.neighbours(pos, adjacency) {
+                if visit(neighbour, seen) {
+                    queue.push_back(neighbour);
+                }
+            }
+        }
+
+        region
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const MAP: &str = "12..\n.#.3\n#..4";
+
+    fn map() -> Grid<char> {
+        Grid::from_chars(3, MAP, |c| c).unwrap()
+    }
+
+    #[test]
+    fn test_runs() {
+        let grid = map();
+        let runs = grid.runs(char::is_ascii_digit);
+
+        assert_eq!(
+            runs,
+            [
+                Run { y: 0, xs: 0..2 },
+                Run { y: 1, xs: 3..4 },
+                Run { y: 2, xs: 3..4 },
+            ]
+        );
+
+        assert_eq!(
+            grid.run_border(&runs[0]),
+            [
+                Pos::new(2, 0),
+                Pos::new(0, 1),
+                Pos::new(1, 1),
+                Pos::new(2, 1)
+            ]
+        );
+        assert_eq!(
+            grid.run_border(&runs[2]),
+            [Pos::new(2, 1), Pos::new(3, 1), Pos::new(2, 2)]
+        );
+    }
+
+    #[test]
+    fn test_regions() {
+        let grid = map();
+
+        let blank = grid.flood_fill(Pos::new(2, 0), Adjacency::Four, |c| *c == '.');
+        assert_eq!(blank.len(), 5);
+        assert!(grid
+            .flood_fill(Pos::new(0, 0), Adjacency::Four, |c| *c == '.')
+            .is_empty());
+
+        let walls = grid.regions(Adjacency::Four, |c| *c == '#');
+        assert_eq!(walls, [vec![Pos::new(1, 1)], vec![Pos::new(0, 2)]]);
+
+        let walls = grid.regions(Adjacency::Eight, |c| *c == '#');
+        assert_eq!(walls, [vec![Pos::new(1, 1), Pos::new(0, 2)]]);
+
+        let numbers = grid.regions(Adjacency::Four, char::is_ascii_digit);
+        assert_eq!(numbers.len(), 2);
+    }
+}
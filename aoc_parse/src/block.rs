@@ -0,0 +1,46 @@
+//! Inputs split into blocks by blank lines, like the maps of day 5.
+
+use aoc_common::Line;
+
+/// Splits `input` into blocks of lines, broken up by blank lines. A run of
+/// blank lines is a single break, so none of the blocks are empty.
+pub fn blocks(day: u8, input: &str) -> Vec<Vec<Line<'_>>> {
+    let mut blocks = Vec::new();
+    let mut block = Vec::new();
+
+    for line in Line::all(day, input) {
+        match line.text.is_empty() {
+            true if !block.is_empty() => blocks.push(std::mem::take(&mut block)),
+            true => {}
+            false => block.push(line),
+        }
+    }
+
+    if !block.is_empty() {
+        blocks.push(block);
+    }
+
+    blocks
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_blocks() {
+        let texts = |input| {
+            blocks(5, input)
+                .iter()
+                .map(|block| block.iter().map(|line| line.text).collect::<Vec<_>>())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(texts("a\nb\n\nc\n"), [vec!["a", "b"], vec!["c"]]);
+        assert_eq!(texts("\na\n\n\n\nb"), [vec!["a"], vec!["b"]]);
+        assert!(texts("").is_empty());
+
+        // Lines keep their numbers in the whole input.
+        assert_eq!(blocks(5, "a\n\nb")[1][0].number, 3);
+    }
+}
@@ -0,0 +1,73 @@
+//! Rectangular maps of characters, like day 3's engine schematic.
+
+use aoc_common::{Line, ParseError};
+
+/// The rows of a map of characters, all of which are `width` characters wide.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CharGrid<'a> {
+    pub width: usize,
+    pub rows: Vec<Line<'a>>,
+}
+
+impl CharGrid<'_> {
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Turns every character into a cell with `cell`, row by row from the top.
+    pub fn cells<T>(&self, cell: impl FnMut(char) -> T) -> Vec<T> {
+        self.rows
+            .iter()
+            .flat_map(|row| row.text.chars())
+            .map(cell)
+            .collect()
+    }
+}
+
+/// Reads `input` as a map of characters. Every row has to be as wide as the
+/// first one, otherwise cells would end up on the wrong rows.
+pub fn char_grid(day: u8, input: &str) -> Result<CharGrid<'_>, ParseError> {
+    let rows = Line::all(day, input).collect::<Vec<_>>();
+
+    let width = match rows.first() {
+        Some(row) if !row.text.is_empty() => row.text.chars().count(),
+        _ => return Err(ParseError::at_end(day, input, "a row of the map")),
+    };
+
+    for row in &rows {
+        if row.text.chars().count() != width {
+            let past_the_width = match row.text.char_indices().nth(width) {
+                Some((i, _)) => &row.text[i..],
+                None => row.end(),
+            };
+
+            return Err(row.error(past_the_width, format!("a row {width} characters wide")));
+        }
+    }
+
+    Ok(CharGrid { width, rows })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_char_grid() {
+        let grid = char_grid(3, "a.b\n.c.").unwrap();
+
+        assert_eq!((grid.width, grid.height()), (3, 2));
+        assert_eq!(
+            grid.cells(|c| c == '.'),
+            [false, true, false, true, false, true]
+        );
+
+        let error = char_grid(3, "abc\nabcd").unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (2, 4, "d"));
+
+        let error = char_grid(3, "abc\nab").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+
+        assert!(char_grid(3, "").is_err());
+    }
+}
@@ -0,0 +1,106 @@
+//! Lines that start with a label and a colon, like `Time:      7  15   30`,
+//! `Game 12: 3 blue, 4 red` or `seed-to-soil map:`.
+
+use aoc_common::{Line, ParseError};
+use std::str::FromStr;
+
+/// Splits `line` at its first colon. Returns the label before it, and the rest
+/// of the line after it without the whitespace it starts with.
+pub fn header<'a>(line: &Line<'a>) -> Result<(&'a str, &'a str), ParseError> {
+    let (label, rest) = line
+        .text
+        .split_once(':')
+        .ok_or_else(|| line.error(line.end(), "`:` after a label"))?;
+
+    Ok((label, rest.trim_start()))
+}
+
+/// Expects `line` to start with `label` and a colon, like `Time:`. Returns the
+/// rest of the line without the whitespace it starts with.
+pub fn labelled<'a>(line: &Line<'a>, label: &str) -> Result<&'a str, ParseError> {
+    let rest = line
+        .text
+        .strip_prefix(label)
+        .and_then(|rest| rest.strip_prefix(':'))
+        .ok_or_else(|| {
+            let found = line.text.split_whitespace().next().unwrap_or(line.end());
+
+            line.error(found, format!("`{label}:`"))
+        })?;
+
+    Ok(rest.trim_start())
+}
+
+/// Parses a header like `Game 12:` or `Card   3:`, where `label` is followed by
+/// whitespace, a number and a colon. Returns the number, and the rest of the
+/// line without the whitespace it starts with.
+pub fn numbered<'a, T: FromStr>(line: &Line<'a>, label: &str) -> Result<(T, &'a str), ParseError> {
+    let name = label.to_lowercase();
+
+    let (head, rest) = line
+        .text
+        .split_once(':')
+        .ok_or_else(|| line.error(line.end(), format!("`:` after the {name} number")))?;
+
+    let number_text = head
+        .strip_prefix(label)
+        .filter(|after| after.starts_with(char::is_whitespace))
+        .ok_or_else(|| line.error(head, format!("a `{label} n:` header")))?
+        .trim_start();
+
+    let number = number_text
+        .parse()
+        .map_err(|_| line.error(number_text, format!("a {name} number")))?;
+
+    Ok((number, rest.trim_start()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn line(text: &str) -> Line<'_> {
+        Line {
+            day: 1,
+            number: 1,
+            text,
+        }
+    }
+
+    #[test]
+    fn test_header() {
+        assert_eq!(
+            header(&line("seed-to-soil map:")).unwrap(),
+            ("seed-to-soil map", "")
+        );
+        assert_eq!(header(&line("seeds 79 14")).unwrap_err().column, 12);
+    }
+
+    #[test]
+    fn test_labelled() {
+        assert_eq!(
+            labelled(&line("Time:      7  15"), "Time").unwrap(),
+            "7  15"
+        );
+
+        let error = labelled(&line("Tim: 7"), "Time").unwrap_err();
+        assert_eq!((error.column, error.text.as_str()), (1, "Tim:"));
+    }
+
+    #[test]
+    fn test_numbered() {
+        assert_eq!(
+            numbered::<u32>(&line("Card   3:  1 21 | 69"), "Card").unwrap(),
+            (3, "1 21 | 69")
+        );
+
+        let error = numbered::<u32>(&line("Game x: 1 red"), "Game").unwrap_err();
+        assert_eq!(
+            (error.column, error.expected.as_str()),
+            (6, "a game number")
+        );
+
+        let error = numbered::<u32>(&line("Gamer 1: 1 red"), "Game").unwrap_err();
+        assert_eq!(error.text, "Gamer 1");
+    }
+}
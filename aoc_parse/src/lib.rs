@@ -0,0 +1,19 @@
+//! Parsers for the formats that keep coming up in puzzle inputs: labelled
+//! headers, lists of numbers, blocks of lines between blank lines,
+//! `key = (a, b)` records and maps of characters.
+//!
+//! Everything works on slices of a [`Line`], so an error points at exactly
+//! the text that couldn't be parsed instead of skipping over it.
+
+pub mod block;
+pub mod grid;
+pub mod header;
+pub mod numbers;
+pub mod record;
+
+pub use aoc_common::{Line, ParseError};
+pub use block::blocks;
+pub use grid::{char_grid, CharGrid};
+pub use header::{header, labelled, numbered};
+pub use numbers::numbers;
+pub use record::{record, Record};
@@ -0,0 +1,32 @@
+//! Lists of numbers separated by whitespace, like `79 14 55 13`.
+
+use aoc_common::{Line, ParseError};
+use std::str::FromStr;
+
+/// Parses every number in `text`, which should be a slice of `line`. Anything
+/// that isn't a number is an error, rather than being skipped.
+pub fn numbers<T: FromStr>(line: &Line, text: &str) -> Result<Vec<T>, ParseError> {
+    text.split_whitespace()
+        .map(|token| token.parse().map_err(|_| line.error(token, "a number")))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_numbers() {
+        let line = Line {
+            day: 9,
+            number: 3,
+            text: "10 13  -16 21",
+        };
+
+        assert_eq!(numbers::<i64>(&line, line.text).unwrap(), [10, 13, -16, 21]);
+        assert_eq!(numbers::<i64>(&line, "").unwrap(), []);
+
+        let error = numbers::<u32>(&line, &line.text[3..]).unwrap_err();
+        assert_eq!((error.column, error.text.as_str()), (8, "-16"));
+    }
+}
@@ -0,0 +1,105 @@
+//! Lines that give a key a pair of values, like `AAA = (BBB, CCC)`.
+
+use aoc_common::{Line, ParseError};
+
+/// A parsed `key = (left, right)` line. Every part is a slice of the line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Record<'a> {
+    pub key: &'a str,
+    pub left: &'a str,
+    pub right: &'a str,
+}
+
+/// Reads a line from the front, one expected piece at a time.
+struct Cursor<'l, 'a> {
+    line: &'l Line<'a>,
+    rest: &'a str,
+}
+
+impl<'a> Cursor<'_, 'a> {
+    /// Takes a name made of ASCII letters and digits.
+    fn name(&mut self) -> Result<&'a str, ParseError> {
+        let end = self
+            .rest
+            .find(|c: char| !c.is_ascii_alphanumeric())
+            .unwrap_or(self.rest.len());
+
+        if end == 0 {
+            return Err(self.line.error(self.rest, "a name of letters and digits"));
+        }
+
+        let (name, rest) = self.rest.split_at(end);
+        self.rest = rest;
+
+        Ok(name)
+    }
+
+    /// Takes exactly `expected`.
+    fn literal(&mut self, expected: &str) -> Result<(), ParseError> {
+        self.rest = self
+            .rest
+            .strip_prefix(expected)
+            .ok_or_else(|| self.line.error(self.rest, format!("`{expected}`")))?;
+
+        Ok(())
+    }
+
+    fn end(&self) -> Result<(), ParseError> {
+        match self.rest.is_empty() {
+            true => Ok(()),
+            false => Err(self.line.error(self.rest, "the end of the line")),
+        }
+    }
+}
+
+/// Parses a line like `AAA = (BBB, CCC)`.
+pub fn record<'a>(line: &Line<'a>) -> Result<Record<'a>, ParseError> {
+    let mut cursor = Cursor {
+        line,
+        rest: line.text,
+    };
+
+    let key = cursor.name()?;
+    cursor.literal(" = (")?;
+    let left = cursor.name()?;
+    cursor.literal(", ")?;
+    let right = cursor.name()?;
+    cursor.literal(")")?;
+    cursor.end()?;
+
+    Ok(Record { key, left, right })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn line(text: &str) -> Line<'_> {
+        Line {
+            day: 8,
+            number: 1,
+            text,
+        }
+    }
+
+    #[test]
+    fn test_record() {
+        assert_eq!(
+            record(&line("AAA = (BBB, 11Z)")).unwrap(),
+            Record {
+                key: "AAA",
+                left: "BBB",
+                right: "11Z"
+            }
+        );
+
+        let error = record(&line("AAA = (BBB,CCC)")).unwrap_err();
+        assert_eq!((error.column, error.expected.as_str()), (11, "`, `"));
+
+        let error = record(&line("AAA = (BBB, CCC) x")).unwrap_err();
+        assert_eq!((error.column, error.text.as_str()), (17, " x"));
+
+        let error = record(&line("AAA = (, CCC)")).unwrap_err();
+        assert_eq!(error.column, 8);
+    }
+}
@@ -0,0 +1,105 @@
+use aoc_common::{Answer, Line, ParseError, Solution};
+use tracing::trace;
+
+pub const INPUT: &str = include_str!("../input.txt");
+
+// This specifies our number system for use in `.to_digit()`
+const NUMBER_SYSTEM_BASE: u32 = 10;
+
+/// Finds the first "standard" digit. "standard" in this context means a numerical representation
+/// of the number, like "1".
+pub fn find_first_standard_digit(line: &str) -> u32 {
+    for c in line.chars() {
+        if let Some(digit) = c.to_digit(NUMBER_SYSTEM_BASE) {
+            return digit;
+        }
+    }
+
+    panic!("If this point is reached, something is wrong with the input data.")
+}
+
+/// Finds the last "standard" digit. "standard" in this context means a numerical representation
+/// of the number, like "1".
+pub fn find_last_standard_digit(line: &str) -> u32 {
+    for c in line.chars().rev() {
+        if let Some(digit) = c.to_digit(NUMBER_SYSTEM_BASE) {
+            return digit;
+        }
+    }
+
+    panic!("If this point is reached, something is wrong with the input data.")
+}
+
+/// Concatenates two standard digits. For example, 2 and 7 becomes 27.
+/// "standard" in this context means a numerical representation of the number, like "1".
+pub fn concat_standard_digits(first_digit: u32, last_digit: u32) -> u32 {
+    (first_digit.to_string() + &last_digit.to_string())
+        .parse()
+        .unwrap()
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<String>;
+
+    const DAY: u8 = 1;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Line::all(Self::DAY, input)
+            .map(|line| {
+                // Every line needs a digit for us to find a calibration value in it.
+                match line.text.chars().any(|c| c.is_digit(NUMBER_SYSTEM_BASE)) {
+                    true => Ok(line.text.to_string()),
+                    false => Err(line.error(line.text, "a line with at least one digit")),
+                }
+            })
+            .collect()
+    }
+
+    /// Sums the calibration values of every line, only counting standard digits.
+    fn part_one(input: &Self::Input) -> Option<Answer> {
+        let mut accumulator = 0;
+
+        for line in input {
+            let first_digit = find_first_standard_digit(line);
+            let last_digit = find_last_standard_digit(line);
+
+            let concatenated_digits = concat_standard_digits(first_digit, last_digit);
+            trace!(
+                line,
+                value = concatenated_digits,
+                "found a calibration value"
+            );
+
+            accumulator += concatenated_digits;
+        }
+
+        Some(accumulator.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet";
+
+    #[test]
+    fn test_example() {
+        let input = Day1::parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day1::part_one(&input), Some(Answer::Unsigned(142)));
+    }
+
+    #[test]
+    fn test_standard_digits() {
+        assert_eq!(find_first_standard_digit("pqr3stu8vwx"), 3);
+        assert_eq!(find_last_standard_digit("pqr3stu8vwx"), 8);
+        assert_eq!(find_last_standard_digit("treb7uchet"), 7);
+        assert_eq!(concat_standard_digits(7, 7), 77);
+    }
+}
@@ -0,0 +1,180 @@
+use aoc_common::{Answer, Line, ParseError, Solution};
+use std::collections::HashMap;
+use tracing::trace;
+
+pub const INPUT: &str = include_str!("../input.txt");
+
+// This specifies our number system for use in `.to_digit()`
+const NUMBER_SYSTEM_BASE: u32 = 10;
+
+const WORD_DIGITS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+pub fn parse_word_digit(word_digit: &str) -> u32 {
+    match word_digit {
+        "one" => 1,
+        "two" => 2,
+        "three" => 3,
+        "four" => 4,
+        "five" => 5,
+        "six" => 6,
+        "seven" => 7,
+        "eight" => 8,
+        "nine" => 9,
+        _ => panic!("`word_digit` should be one of the words in WORD_DIGITS."),
+    }
+}
+
+/// Returns a `HashMap` of the byte index of the digit for the key
+/// and the digit for the value. Standard digit in this context refers
+/// to the digits being strings like "1" and "7".
+pub fn find_standard_digits(line: &str) -> HashMap<usize, u32> {
+    let mut standard_digits_found = HashMap::new();
+
+    for (i, c) in line.char_indices() {
+        if let Some(digit) = c.to_digit(NUMBER_SYSTEM_BASE) {
+            standard_digits_found.insert(i, digit);
+        }
+    }
+
+    standard_digits_found
+}
+
+/// Returns a `HashMap` of the byte index of the digit for the key
+/// and the digit for the value. Word digit in this context refers to digits
+/// found as strings in the form of "one" and "seven"
+pub fn find_word_digits(line: &str) -> HashMap<usize, u32> {
+    let mut word_digits_found = HashMap::new();
+
+    for word_digit_str in WORD_DIGITS {
+        // We check whether the word digit starts at every character of the
+        // line. Words can overlap, like in "eightwo", so we can't just split
+        // the line up on them.
+        for (start_index, _) in line.char_indices() {
+            if line[start_index..].starts_with(word_digit_str) {
+                let parsed_digit = parse_word_digit(word_digit_str);
+                word_digits_found.insert(start_index, parsed_digit);
+            }
+        }
+    }
+
+    word_digits_found
+}
+
+pub fn concat_digits(first_digit: u32, last_digit: u32) -> u32 {
+    (first_digit * 10) + last_digit
+}
+
+/// Finds the standard digits and word digits in a line.
+/// Returns them as a `HashMap` where the key is the index where
+/// it was found and the value is the digit.
+pub fn find_digits(line: &str) -> HashMap<usize, u32> {
+    let mut digits = HashMap::new();
+
+    let standard_digits = find_standard_digits(line);
+    let word_digits = find_word_digits(line);
+
+    digits.extend(standard_digits);
+    digits.extend(word_digits);
+
+    digits
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<String>;
+
+    const DAY: u8 = 1;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Line::all(Self::DAY, input)
+            .map(|line| {
+                // Every line needs a digit (either kind) for us to find a
+                // calibration value in it.
+                let has_standard_digit = line.text.chars().any(|c| c.is_digit(NUMBER_SYSTEM_BASE));
+                let has_word_digit = WORD_DIGITS
+                    .iter()
+                    .any(|word_digit| line.text.contains(word_digit));
+
+                match has_standard_digit || has_word_digit {
+                    true => Ok(line.text.to_string()),
+                    false => Err(line.error(line.text, "a line with at least one digit")),
+                }
+            })
+            .collect()
+    }
+
+    /// Sums the calibration values of every line, counting both standard digits
+    /// and word digits.
+    fn part_two(input: &Self::Input) -> Option<Answer> {
+        let mut accumulator = 0;
+
+        for line in input {
+            let digits = find_digits(line);
+
+            let mut digit_indices = digits.keys().copied().collect::<Vec<usize>>();
+            digit_indices.sort();
+
+            let first_digit = digits[digit_indices.first().unwrap()];
+            let last_digit = digits[digit_indices.last().unwrap()];
+
+            let calibration_value = concat_digits(first_digit, last_digit);
+            trace!(line, value = calibration_value, "found a calibration value");
+
+            accumulator += calibration_value;
+        }
+
+        Some(accumulator.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen";
+
+    #[test]
+    fn test_example() {
+        let input = Day1::parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day1::part_two(&input), Some(Answer::Unsigned(281)));
+    }
+
+    #[test]
+    fn test_edge_cases() {
+        let input = r#"eightveight
+v4"#;
+
+        let mut answers = vec![HashMap::new(); 2];
+
+        answers[0].insert(0, 8);
+        answers[0].insert(6, 8);
+
+        answers[1].insert(1, 4);
+
+        let found_answers = input
+            .lines()
+            .map(find_digits)
+            .collect::<Vec<HashMap<usize, u32>>>();
+
+        assert_eq!(answers, found_answers);
+    }
+
+    #[test]
+    fn test_unusual_lines() {
+        assert!(find_digits("").is_empty());
+
+        // "é" is two bytes long, so the digits are found at bytes 2 and 3.
+        assert_eq!(find_digits("é1one"), HashMap::from([(2, 1), (3, 1)]));
+        assert_eq!(find_digits("tw\u{540}one"), HashMap::from([(4, 1)]));
+    }
+}
@@ -0,0 +1,307 @@
+use aoc_common::{Answer, Line, ParseError, Solution};
+use std::fmt;
+use tracing::trace;
+
+pub const INPUT: &str = include_str!("../input.txt");
+
+#[derive(Debug)]
+pub struct Bag {
+    pub red: u32,
+    pub green: u32,
+    pub blue: u32,
+}
+
+impl Bag {
+    pub fn generate_smallest_bag(game: &Game) -> Self {
+        let mut highest_red = 0;
+        let mut highest_green = 0;
+        let mut highest_blue = 0;
+
+        for set in &game.sets {
+            if set.red > highest_red {
+                highest_red = set.red;
+            }
+
+            if set.green > highest_green {
+                highest_green = set.green;
+            }
+
+            if set.blue > highest_blue {
+                highest_blue = set.blue;
+            }
+        }
+
+        Self {
+            red: highest_red,
+            green: highest_green,
+            blue: highest_blue,
+        }
+    }
+
+    pub fn is_valid(&self, game: &Game) -> bool {
+        let valid_sets = game
+            .sets
+            .iter()
+            .filter(|set| {
+                (set.red <= self.red) && (set.green <= self.green) && (set.blue <= self.blue)
+            })
+            .count();
+
+        // If the amount of valid sets are the same as the amount of sets,
+        // all sets are valid.
+        valid_sets == game.sets.len()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Set {
+    pub red: u32,
+    pub green: u32,
+    pub blue: u32,
+}
+
+impl Set {
+    /// Parses a set from `raw`, which is a slice of `line` like "3 blue, 4 red".
+    pub fn new(line: &Line, raw: &str) -> Result<Self, ParseError> {
+        let mut red = 0;
+        let mut green = 0;
+        let mut blue = 0;
+
+        for chunk in raw.split(", ") {
+            // The chunks will look like "6 red". We split them up in the middle
+            // and then parse them from there.
+            let split = chunk.split_whitespace().collect::<Vec<&str>>();
+
+            let amount_string = split.first().copied().unwrap_or(chunk);
+            let amount: u32 = amount_string
+                .parse()
+                .map_err(|_| line.error(amount_string, "an amount of cubes"))?;
+
+            let color_string = split.get(1).copied().unwrap_or(&chunk[chunk.len()..]);
+
+            let total: &mut u32 = match color_string {
+                "red" => &mut red,
+                "green" => &mut green,
+                "blue" => &mut blue,
+                _ => return Err(line.error(color_string, "`red`, `green` or `blue`")),
+            };
+
+            // A color can come up more than once in a set, so its amounts
+            // have to add up to something we can still count.
+            *total = total.checked_add(amount).ok_or_else(|| {
+                line.error(
+                    amount_string,
+                    format!("amounts of a color adding up to at most {}", u32::MAX),
+                )
+            })?;
+        }
+
+        Ok(Self { red, green, blue })
+    }
+}
+
+/// Writes the set like "4 red, 3 blue", leaving out colors with no cubes. An
+/// empty set is written as "0 red" so that it can still be parsed.
+impl fmt::Display for Set {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let colors = [
+            ("red", self.red),
+            ("green", self.green),
+            ("blue", self.blue),
+        ];
+        let mut shown = colors.iter().filter(|(_, amount)| *amount > 0).peekable();
+
+        if shown.peek().is_none() {
+            return write!(f, "0 red");
+        }
+
+        for (i, (color, amount)) in shown.enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+
+            write!(f, "{amount} {color}")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Game {
+    pub id: u32,
+    pub sets: Vec<Set>,
+}
+
+impl Game {
+    pub fn new(line: &Line) -> Result<Self, ParseError> {
+        let (id, sets_raw) = aoc_parse::numbered(line, "Game")?;
+
+        let sets = sets_raw
+            .split("; ")
+            .map(|raw| Set::new(line, raw))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { id, sets })
+    }
+}
+
+impl fmt::Display for Game {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Game {}: ", self.id)?;
+
+        for (i, set) in self.sets.iter().enumerate() {
+            if i > 0 {
+                write!(f, "; ")?;
+            }
+
+            write!(f, "{set}")?;
+        }
+
+        Ok(())
+    }
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Game>;
+
+    const DAY: u8 = 2;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Line::all(Self::DAY, input)
+            .map(|line| Game::new(&line))
+            .collect()
+    }
+
+    /// Sums the ids of the games that are possible with a bag of 12 red,
+    /// 13 green and 14 blue cubes.
+    fn part_one(input: &Self::Input) -> Option<Answer> {
+        let bag = Bag {
+            red: 12,
+            green: 13,
+            blue: 14,
+        };
+
+        let sum_of_ids = input
+            .iter()
+            .filter_map(|game| {
+                let possible = bag.is_valid(game);
+                trace!(game = game.id, possible, "checked a game");
+
+                match possible {
+                    true => Some(u64::from(game.id)),
+                    false => None,
+                }
+            })
+            .sum::<u64>();
+
+        Some(sum_of_ids.into())
+    }
+
+    /// Sums the powers of the smallest bag that each game could be played with.
+    /// There's no answer if the sum doesn't fit in a u64.
+    fn part_two(input: &Self::Input) -> Option<Answer> {
+        let power_sum = input.iter().try_fold(0_u64, |power_sum, game| {
+            let smallest_bag = Bag::generate_smallest_bag(game);
+            let power = u64::from(smallest_bag.red)
+                .checked_mul(smallest_bag.green.into())?
+                .checked_mul(smallest_bag.blue.into())?;
+            trace!(game = game.id, power, "found the smallest bag");
+
+            power_sum.checked_add(power)
+        })?;
+
+        Some(power_sum.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+    #[test]
+    fn test_example() {
+        let input = Day2::parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day2::part_one(&input), Some(Answer::Unsigned(8)));
+        assert_eq!(Day2::part_two(&input), Some(Answer::Unsigned(2286)));
+    }
+
+    #[test]
+    fn test_bag() {
+        let games = Day2::parse(EXAMPLE).unwrap();
+        let bag = Bag {
+            red: 12,
+            green: 13,
+            blue: 14,
+        };
+
+        assert!(bag.is_valid(&games[0]));
+        assert!(!bag.is_valid(&games[2]));
+
+        let smallest_bag = Bag::generate_smallest_bag(&games[0]);
+
+        assert_eq!(
+            (smallest_bag.red, smallest_bag.green, smallest_bag.blue),
+            (4, 2, 6)
+        );
+    }
+
+    #[test]
+    fn test_overflow() {
+        let error = Day2::parse("Game 1: 4294967295 red, 1 red").unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.text.as_str()),
+            (1, 25, "1")
+        );
+
+        // Each power fits, but not the three of them multiplied together.
+        let games = Day2::parse("Game 1: 4294967295 red, 4294967295 green, 2 blue").unwrap();
+        assert_eq!(Day2::part_one(&games), Some(Answer::Unsigned(0)));
+        assert_eq!(Day2::part_two(&games), None);
+
+        let games = Day2::parse("Game 1: 2000 red, 2000 green, 2000 blue").unwrap();
+        assert_eq!(
+            Day2::part_two(&games),
+            Some(Answer::Unsigned(8_000_000_000))
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let games = Day2::parse(EXAMPLE).unwrap();
+
+        assert_eq!(
+            games[0].to_string(),
+            "Game 1: 4 red, 3 blue; 1 red, 2 green, 6 blue; 2 green"
+        );
+        assert_eq!(
+            Set {
+                red: 0,
+                green: 0,
+                blue: 0
+            }
+            .to_string(),
+            "0 red"
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let games = Day2::parse(INPUT).unwrap();
+        let printed = games
+            .iter()
+            .map(|game| format!("{game}\n"))
+            .collect::<String>();
+
+        assert_eq!(Day2::parse(&printed).unwrap(), games);
+    }
+}
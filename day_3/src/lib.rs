@@ -0,0 +1,302 @@
+use aoc_common::{Answer, ParseError, Solution};
+use aoc_grid::{Grid, Pos, Run};
+use std::collections::HashMap;
+use tracing::{debug, trace};
+
+pub const INPUT: &str = include_str!("../input.txt");
+
+pub fn find_ships_attached_to_gears(ships: Vec<Ship>) -> Vec<[Ship; 2]> {
+    // A hashmap that shows the count of ships beside each gear we find.
+    // If a gear has exactly two ships adjacent to it, we include
+    // that pair of ships in the return value.
+    let mut found_gears: HashMap<UnitWithCoords, Vec<Ship>> = HashMap::new();
+
+    for ship in ships {
+        for unit_with_coords in ship.clone().borders {
+            if let Unit::Symbol(symbol) = unit_with_coords.unit {
+                if symbol == '*' {
+                    match found_gears.get_mut(&unit_with_coords) {
+                        Some(ships_found) => ships_found.push(ship.clone()),
+                        None => {
+                            found_gears.insert(unit_with_coords, vec![ship.clone()]);
+                        }
+                    };
+                }
+            }
+        }
+    }
+
+    let pairs = found_gears
+        .values()
+        .filter_map(|found_ships| match found_ships.len() {
+            2 => Some(found_ships.clone().try_into().unwrap()),
+            _ => None,
+        })
+        .collect::<Vec<[Ship; 2]>>();
+
+    debug!(
+        gears = found_gears.len(),
+        with_two_ships = pairs.len(),
+        "found the gears"
+    );
+
+    pairs
+}
+
+/// Contains clusters of numbers horizontally adjacent to each other,
+/// along with the bordering units. Naming it Ships cause it reminds
+/// me of Battleship.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Ship {
+    pub body: Vec<UnitWithCoords>,
+    pub borders: Vec<UnitWithCoords>,
+}
+
+impl Ship {
+    pub fn is_valid(&self) -> bool {
+        for unit_with_coords in &self.borders {
+            if let Unit::Symbol(_) = unit_with_coords.unit {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    pub fn as_number(&self) -> usize {
+        let digits_as_string =
+            self.body
+                .iter()
+                .map(|unit_with_coords| match unit_with_coords.unit {
+                    Unit::Digit(digit) => digit.to_string(),
+                    _ => panic!("The body should only be comprised of digits."),
+                });
+
+        let mut concatenated_digits_string = String::new();
+
+        for digit_string in digits_as_string {
+            concatenated_digits_string.push_str(&digit_string);
+        }
+
+        concatenated_digits_string.parse().unwrap()
+    }
+}
+
+/// A type wrapper over a [`Grid`]. Our (0,0) on the grid is the top left corner,
+/// with the y value increasing as you go down.
+pub struct EngineSchematic(pub Grid<Unit>);
+
+impl EngineSchematic {
+    /// Finds every number in the schematic, reading each row from the left.
+    pub fn find_ships(&self) -> Vec<Ship> {
+        let ships = self
+            .0
+            .runs(|unit| matches!(unit, Unit::Digit(_)))
+            .iter()
+            .map(|run| self.build_out_ship(run))
+            .collect::<Vec<_>>();
+
+        debug!(ships = ships.len(), "found the ships");
+
+        ships
+    }
+
+    /// Turns a run of digits into a [`Ship`], along with every unit
+    /// around it, diagonals included.
+    pub fn build_out_ship(&self, run: &Run) -> Ship {
+        Ship {
+            body: self.units_at(run.positions()),
+            borders: self.units_at(self.0.run_border(run)),
+        }
+    }
+
+    fn units_at(&self, positions: impl IntoIterator<Item = Pos>) -> Vec<UnitWithCoords> {
+        positions
+            .into_iter()
+            .filter_map(|pos| self.get_unit_with_coords(pos))
+            .collect()
+    }
+
+    pub fn get_unit_with_coords(&self, pos: Pos) -> Option<UnitWithCoords> {
+        self.0
+            .get(pos)
+            .copied()
+            .map(|unit| UnitWithCoords { pos, unit })
+    }
+}
+
+/// This is sort of a wrapper over [`Unit`] that allows us to store the position of
+/// the unit as well.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UnitWithCoords {
+    pub pos: Pos,
+    pub unit: Unit,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Unit {
+    Blank,
+    Symbol(char),
+    Digit(u8),
+}
+
+impl Unit {
+    pub fn new(c: char) -> Self {
+        if let Some(parsed_digit) = c.to_digit(10) {
+            return Self::Digit(parsed_digit as u8);
+        }
+
+        match c {
+            '.' => Self::Blank,
+            _ => Self::Symbol(c),
+        }
+    }
+}
+
+pub fn generate_engine_schematic(input: &str) -> Result<EngineSchematic, ParseError> {
+    let schematic = aoc_parse::char_grid(Day3::DAY, input)?;
+
+    // Every number has to fit in a `usize` for us to read it.
+    for row in &schematic.rows {
+        let numbers = row
+            .text
+            .split(|c: char| !c.is_ascii_digit())
+            .filter(|number| !number.is_empty());
+
+        for number in numbers {
+            if number.parse::<usize>().is_err() {
+                return Err(row.error(number, format!("a number up to {}", usize::MAX)));
+            }
+        }
+    }
+
+    Ok(EngineSchematic(Grid::new(
+        schematic.width,
+        schematic.cells(Unit::new),
+    )))
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = EngineSchematic;
+
+    const DAY: u8 = 3;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        generate_engine_schematic(input)
+    }
+
+    /// Sums the numbers of every ship that borders a symbol. There's no answer
+    /// if the sum doesn't fit in a `usize`.
+    fn part_one(input: &Self::Input) -> Option<Answer> {
+        let ships = input.find_ships();
+
+        let valid_ship_number_sum = ships
+            .iter()
+            .filter(|ship| {
+                let valid = ship.is_valid();
+                trace!(number = ship.as_number(), valid, "checked a ship");
+
+                valid
+            })
+            .map(Ship::as_number)
+            .try_fold(0_usize, usize::checked_add)?;
+
+        Some(valid_ship_number_sum.into())
+    }
+
+    /// Sums the gear ratios of every gear that has exactly two ships attached to it.
+    /// There's no answer if a ratio or the sum doesn't fit in a `usize`.
+    fn part_two(input: &Self::Input) -> Option<Answer> {
+        let ships = input.find_ships();
+
+        let ships_attached_to_gears = find_ships_attached_to_gears(ships);
+
+        let sum_of_gear_ratios =
+            ships_attached_to_gears
+                .iter()
+                .try_fold(0_usize, |sum, ship_pair| {
+                    let gear_ratio = ship_pair[0]
+                        .as_number()
+                        .checked_mul(ship_pair[1].as_number())?;
+
+                    sum.checked_add(gear_ratio)
+                })?;
+
+        Some(sum_of_gear_ratios.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+
+    #[test]
+    fn test_example() {
+        let input = Day3::parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day3::part_one(&input), Some(Answer::Unsigned(4361)));
+        assert_eq!(Day3::part_two(&input), Some(Answer::Unsigned(467835)));
+    }
+
+    #[test]
+    fn test_trailing_spaces() {
+        // Spaces after the first row would make the grid wider than the rest.
+        let pasted = EXAMPLE.replacen('\n', "   \n", 1);
+        let input = aoc_common::solution::parse::<Day3>(&pasted).unwrap();
+
+        assert!(Day3::parse(&pasted).is_err());
+        assert_eq!(Day3::part_one(&input), Some(Answer::Unsigned(4361)));
+    }
+
+    #[test]
+    fn test_find_ships() {
+        let schematic = generate_engine_schematic(EXAMPLE).unwrap();
+        let ships = schematic.find_ships();
+
+        let numbers = ships.iter().map(Ship::as_number).collect::<Vec<_>>();
+        assert_eq!(numbers, [467, 114, 35, 633, 617, 58, 592, 755, 664, 598]);
+
+        // 114 and 58 are the only numbers without a symbol next to them.
+        let invalid = ships
+            .iter()
+            .filter(|ship| !ship.is_valid())
+            .map(Ship::as_number)
+            .collect::<Vec<_>>();
+        assert_eq!(invalid, [114, 58]);
+
+        assert_eq!(find_ships_attached_to_gears(ships).len(), 2);
+    }
+
+    #[test]
+    fn test_number_too_big() {
+        let rows = format!("{:.<21}\n.{}", "1.*", "9".repeat(20));
+        let Err(error) = Day3::parse(&rows) else {
+            panic!("a 20 digit number should be too big");
+        };
+
+        assert_eq!((error.line, error.column), (2, 2));
+    }
+
+    #[test]
+    fn test_answer_too_big() {
+        let input = Day3::parse("99999999999*99999999999").unwrap();
+        assert_eq!(Day3::part_two(&input), None);
+
+        let number = usize::MAX.to_string();
+        let input = Day3::parse(&format!("{number}*\n{number}.")).unwrap();
+        assert_eq!(Day3::part_one(&input), None);
+    }
+}
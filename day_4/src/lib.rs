@@ -0,0 +1,346 @@
+use aoc_common::{Answer, Line, ParseError, Solution};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use tracing::trace;
+
+pub const INPUT: &str = include_str!("../input.txt");
+
+pub struct GameTracker {
+    tracked_games: HashMap<u32, u64>,
+    current_game: u32,
+    games: Vec<Game>,
+}
+
+impl GameTracker {
+    pub fn new(games: Vec<Game>) -> Self {
+        // We go ahead populate the tracked games. We start
+        // with one copy of each card.
+        let mut tracked_games = HashMap::new();
+        let starting_game = 1;
+
+        for id in starting_game as usize..=games.len() {
+            tracked_games.insert(id as u32, 1);
+        }
+
+        Self {
+            tracked_games,
+            current_game: starting_game,
+            games,
+        }
+    }
+
+    /// Steps through the next game. Returns true if another step can
+    /// be made, and returns false if the [`GameTracker`] has terminated.
+    /// Returns `None`, without taking the step, if it would win more copies
+    /// of a card than fit in a u64.
+    pub fn step(&mut self) -> Option<bool> {
+        let Some(game) = self.games.get(self.current_game as usize - 1) else {
+            return Some(false);
+        };
+
+        // We increment the next instances of the upcoming card ids by the amount
+        // of cards of the game we just scratched off.
+        //
+        // We can unwrap these because we already populated the HashMap
+        // on initialization of [`GameTracker`].
+        let increment_amount = *self.tracked_games.get(&self.current_game).unwrap();
+
+        let matches = game.calculate_matches();
+        trace!(
+            card = self.current_game,
+            matches,
+            copies = increment_amount,
+            "scratched a card"
+        );
+
+        // We get the ids of the cards that we need to scratch off after this
+        let upcoming_card_ids = (1..=matches).map(|game_index| game_index + self.current_game);
+
+        // Cards past the end of the table can't be won, so we skip those.
+        // Every new count is worked out before any of them are changed, so
+        // that a step that overflows leaves the tracker as it was.
+        let new_counts = upcoming_card_ids
+            .filter_map(|id| Some((id, *self.tracked_games.get(&id)?)))
+            .map(|(id, card_count)| Some((id, card_count.checked_add(increment_amount)?)))
+            .collect::<Option<Vec<_>>>()?;
+
+        self.tracked_games.extend(new_counts);
+        self.current_game += 1;
+
+        Some(self.current_game as usize <= self.games.len())
+    }
+
+    /// How many scratchcards we have so far, or `None` if that doesn't fit in
+    /// a u64.
+    pub fn scratchcards_used(&self) -> Option<u64> {
+        self.tracked_games
+            .values()
+            .try_fold(0_u64, |used, &copies| used.checked_add(copies))
+    }
+
+    /// Where the card the next step scratches off is in the table, counting
+    /// from 1. It's past the last card once the tracker has terminated.
+    pub fn current_game(&self) -> u32 {
+        self.current_game
+    }
+
+    /// How many copies we have so far of the card at `position`, counting
+    /// from 1 like [`Self::current_game`].
+    pub fn copies(&self, position: u32) -> u64 {
+        self.tracked_games
+            .get(&position)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub fn games(&self) -> &[Game] {
+        &self.games
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    pub id: u32,
+    pub winning_numbers: Vec<u32>,
+    pub available_numbers: Vec<u32>,
+}
+
+impl Game {
+    pub fn new(line: &Line) -> Result<Self, ParseError> {
+        // We separate the raw strings in the input.
+        let (id, numbers_raw) = aoc_parse::numbered(line, "Card")?;
+        let (winning_numbers_raw, available_numbers_raw) = numbers_raw
+            .split_once('|')
+            .ok_or_else(|| line.error(line.end(), "`|` between the two lists of numbers"))?;
+
+        let winning_numbers = aoc_parse::numbers(line, winning_numbers_raw)?;
+        let available_numbers = aoc_parse::numbers(line, available_numbers_raw)?;
+
+        Ok(Self {
+            id,
+            winning_numbers,
+            available_numbers,
+        })
+    }
+
+    pub fn calculate_matches(&self) -> u32 {
+        let winning_numbers_hashset = {
+            let mut winning_numbers_hashset = HashSet::new();
+
+            for number in &self.winning_numbers {
+                winning_numbers_hashset.insert(*number);
+            }
+
+            winning_numbers_hashset
+        };
+
+        self.available_numbers
+            .iter()
+            .filter_map(|number| winning_numbers_hashset.get(number))
+            .count() as u32
+    }
+}
+
+/// Writes the card with its id and numbers padded to line up in columns, like
+/// "Card   1: 41 48 83 | 83 86  6".
+impl fmt::Display for Game {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let write_numbers = |f: &mut fmt::Formatter<'_>, numbers: &[u32]| -> fmt::Result {
+            for number in numbers {
+                write!(f, " {number:>2}")?;
+            }
+
+            Ok(())
+        };
+
+        write!(f, "Card {:>3}:", self.id)?;
+        write_numbers(f, &self.winning_numbers)?;
+        write!(f, " |")?;
+        write_numbers(f, &self.available_numbers)
+    }
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<Game>;
+
+    const DAY: u8 = 4;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Line::all(Self::DAY, input)
+            .map(|line| Game::new(&line))
+            .collect()
+    }
+
+    /// Sums the point values of every card. There's no answer if the sum
+    /// doesn't fit in a u64.
+    fn part_one(input: &Self::Input) -> Option<Answer> {
+        let sum_of_game_values = input.iter().try_fold(0_u64, |sum, game| {
+            let matches = game.calculate_matches();
+            let value = match matches {
+                0 => 0,
+                _ => 2_u64.checked_pow(matches - 1)?,
+            };
+            trace!(card = game.id, matches, value, "scored a card");
+
+            sum.checked_add(value)
+        })?;
+
+        Some(sum_of_game_values.into())
+    }
+
+    /// Counts the scratchcards we end up with after winning copies of the cards below.
+    /// There's no answer if there are more than fit in a u64.
+    fn part_two(input: &Self::Input) -> Option<Answer> {
+        let mut game_tracker = GameTracker::new(input.clone());
+
+        // Loop through all the steps.
+        while game_tracker.step()? {}
+
+        Some(game_tracker.scratchcards_used()?.into())
+    }
+
+    /// A card can't win copies of cards past the end of the table, so part two
+    /// leaves them out.
+    fn check(input: &Self::Input) -> Vec<String> {
+        // Copies are won of the cards that come after a card in the table,
+        // whatever their ids are.
+        input
+            .iter()
+            .enumerate()
+            .filter_map(|(index, game)| {
+                let after = input.len() - index - 1;
+                let matches = game.calculate_matches() as usize;
+
+                (matches > after).then(|| {
+                    format!(
+                        "card {} wins copies of the {matches} cards after it, but only {after} come after it",
+                        game.id
+                    )
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+    #[test]
+    fn test_example() {
+        let input = Day4::parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day4::part_one(&input), Some(Answer::Unsigned(13)));
+        assert_eq!(Day4::part_two(&input), Some(Answer::Unsigned(30)));
+    }
+
+    #[test]
+    fn test_game_tracker() {
+        let games = Day4::parse(EXAMPLE).unwrap();
+
+        let matches = games
+            .iter()
+            .map(Game::calculate_matches)
+            .collect::<Vec<_>>();
+        assert_eq!(matches, [4, 2, 2, 1, 0, 0]);
+
+        let mut tracker = GameTracker::new(games);
+        assert_eq!(tracker.scratchcards_used(), Some(6));
+
+        // Scratching card 1 wins a copy of each of cards 2 to 5.
+        assert_eq!(tracker.step(), Some(true));
+        assert_eq!(tracker.scratchcards_used(), Some(10));
+        assert_eq!(tracker.current_game(), 2);
+        assert_eq!((tracker.copies(5), tracker.copies(6)), (2, 1));
+
+        while tracker.step().unwrap() {}
+        assert_eq!(tracker.scratchcards_used(), Some(30));
+
+        assert_eq!(GameTracker::new(Vec::new()).step(), Some(false));
+
+        // Copies of cards past the end of the table aren't won.
+        let games = Day4::parse("Card 1: 1 2 | 1 2").unwrap();
+        assert_eq!(Day4::part_two(&games), Some(Answer::Unsigned(1)));
+    }
+
+    #[test]
+    fn test_answers_too_big() {
+        let numbers = |count| (1..=count).map(|n| format!(" {n}")).collect::<String>();
+
+        // A card with 65 matches is worth 2^64 points.
+        let card = format!("Card 1:{} |{}", numbers(65), numbers(65));
+        let games = Day4::parse(&card).unwrap();
+        assert_eq!(Day4::part_one(&games), None);
+        assert_eq!(Day4::part_two(&games), Some(Answer::Unsigned(1)));
+
+        // Cards with 10 matches each about double the copies won every time.
+        let cards = (1..=70)
+            .map(|id| format!("Card {id}:{} |{}\n", numbers(10), numbers(10)))
+            .collect::<String>();
+        let games = Day4::parse(&cards).unwrap();
+        assert_eq!(Day4::part_two(&games), None);
+
+        // The step that would overflow isn't taken.
+        let mut tracker = GameTracker::new(games);
+        while let Some(true) = tracker.step() {}
+        assert_eq!(tracker.current_game(), 65);
+        assert_eq!(tracker.step(), None);
+    }
+
+    #[test]
+    fn test_check() {
+        // The card with id 2 has two matches, but there's only one card
+        // after it.
+        let games = Day4::parse(
+            "Card 5: 1 2 | 3 4
+Card 2: 1 2 | 1 2
+Card 9: 5 6 | 7 8",
+        )
+        .unwrap();
+        assert_eq!(
+            Day4::check(&games),
+            ["card 2 wins copies of the 2 cards after it, but only 1 come after it"]
+        );
+
+        assert_eq!(
+            Day4::check(&Day4::parse(EXAMPLE).unwrap()),
+            Vec::<String>::new()
+        );
+        assert_eq!(
+            Day4::check(&Day4::parse(INPUT).unwrap()),
+            Vec::<String>::new()
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let games = Day4::parse(EXAMPLE).unwrap();
+
+        assert_eq!(
+            games[2].to_string(),
+            "Card   3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1"
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let games = Day4::parse(INPUT).unwrap();
+        let printed = games
+            .iter()
+            .map(|game| format!("{game}\n"))
+            .collect::<String>();
+
+        // The real input is already written the canonical way.
+        assert_eq!(printed.trim_end(), INPUT.trim_end());
+        assert_eq!(Day4::parse(&printed).unwrap(), games);
+    }
+}
@@ -0,0 +1,434 @@
+use aoc_common::progress::{self, Cancelled, Progress};
+use aoc_common::{Answer, Line, ParseError, Part, Solution};
+use rayon::prelude::*;
+use std::fmt;
+use tracing::{debug, debug_span, trace};
+
+pub const INPUT: &str = include_str!("../input.txt");
+
+/// How many seeds part two converts between checking in with its progress.
+const SEEDS_PER_CHUNK: usize = 1 << 16;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct MapLine {
+    pub destination_range_start: i64,
+    pub source_range_start: i64,
+    pub range: i64,
+}
+
+impl MapLine {
+    pub fn new(line: &Line) -> Result<Self, ParseError> {
+        let split = line.text.split_whitespace().collect::<Vec<&str>>();
+
+        // Parses the number at `index` in the line, describing it as `name` if
+        // it's missing or malformed.
+        let parse_number = |index: usize, name: &str| -> Result<i64, ParseError> {
+            let raw = split.get(index).copied().unwrap_or(line.end());
+
+            raw.parse().map_err(|_| line.error(raw, name))
+        };
+
+        let destination_range_start = parse_number(0, "a destination range start")?;
+        let source_range_start = parse_number(1, "a source range start")?;
+        let range = parse_number(2, "a range length")?;
+
+        // Both ranges have to end where an i64 can still hold the number, so
+        // converting a seed can't overflow.
+        if source_range_start.checked_add(range).is_none()
+            || destination_range_start.checked_add(range).is_none()
+        {
+            return Err(line.error(
+                split[2],
+                format!("a range length that keeps both ranges up to {}", i64::MAX),
+            ));
+        }
+
+        Ok(Self {
+            destination_range_start,
+            source_range_start,
+            range,
+        })
+    }
+
+    /// Converts an input according to the given map line.
+    /// Returns Some(x) if the mapping changed the value,
+    /// None otherwise.
+    pub fn convert(&self, input: i64) -> Option<i64> {
+        match (self.source_range_start..self.source_range_start + self.range).contains(&input) {
+            // Going through the distance from the start keeps every step
+            // inside one of the ranges, which can't overflow.
+            true => Some(input - self.source_range_start + self.destination_range_start),
+            false => None,
+        }
+    }
+}
+
+impl fmt::Display for MapLine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {}",
+            self.destination_range_start, self.source_range_start, self.range
+        )
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Map {
+    /// The name from the map's header, like `seed-to-soil`.
+    pub name: String,
+    pub map_lines: Vec<MapLine>,
+}
+
+impl Map {
+    /// Converts a seed into the correct mapping of the seed.
+    pub fn convert(&self, input: i64) -> i64 {
+        self.map_lines
+            .iter()
+            .flat_map(|map_line| map_line.convert(input))
+            .min()
+            .unwrap_or(input)
+    }
+}
+
+impl Map {
+    /// Parses a map from its `<name> map:` header and the lines after it.
+    pub fn new(header: &Line, lines: &[Line]) -> Result<Self, ParseError> {
+        let name = header
+            .text
+            .strip_suffix(" map:")
+            .ok_or_else(|| header.error(header.text, "a `<name> map:` header"))?
+            .to_string();
+
+        let map_lines = lines.iter().map(MapLine::new).collect::<Result<_, _>>()?;
+
+        Ok(Self { name, map_lines })
+    }
+}
+
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} map:", self.name)?;
+
+        for map_line in &self.map_lines {
+            write!(f, "\n{map_line}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The parsed almanac: the seeds to plant, followed by every map in the
+/// order they have to be applied.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Almanac {
+    pub seeds: Vec<i64>,
+    pub maps: Vec<Map>,
+}
+
+impl Almanac {
+    /// Takes a seed through every map in turn, giving back its location.
+    pub fn location(&self, seed: i64) -> i64 {
+        self.maps.iter().fold(seed, |seed, map| map.convert(seed))
+    }
+
+    /// Finds the lowest location that any of the seeds map to, reading the
+    /// seeds as pairs of a range start and a range length. The real input
+    /// has billions of seeds, so rather than holding them all at once this
+    /// works through the ranges a chunk at a time, reporting each chunk it's
+    /// converted. Returns `None` if every range is empty.
+    pub fn lowest_location_of_ranges(&self, progress: &Progress) -> Result<Option<i64>, Cancelled> {
+        let total_seeds = self.seeds.chunks(2).fold(0_u64, |total, pair| {
+            total.saturating_add(pair[1].max(0) as u64)
+        });
+
+        debug!(seeds = total_seeds, "counted the seeds in the ranges");
+        progress.start(Some(total_seeds));
+
+        // The parser makes sure every range ends where an i64 can still hold
+        // the number.
+        let chunks = self.seeds.chunks(2).flat_map(|pair| {
+            let end = pair[0] + pair[1];
+
+            (pair[0]..end)
+                .step_by(SEEDS_PER_CHUNK)
+                .map(move |start| start..end.min(start.saturating_add(SEEDS_PER_CHUNK as i64)))
+        });
+
+        chunks
+            .par_bridge()
+            .map(|chunk| {
+                let seeds = (chunk.end - chunk.start) as u64;
+                let lowest = chunk.map(|seed| self.location(seed)).min();
+                trace!(seeds, lowest, "converted a chunk of seeds");
+
+                progress.advance(seeds)?;
+
+                Ok(lowest)
+            })
+            .try_reduce(|| None, |a, b| Ok(a.into_iter().chain(b).min()))
+    }
+}
+
+/// Writes the whole almanac, with a blank line between the seeds and each map.
+impl fmt::Display for Almanac {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "seeds:")?;
+
+        for seed in &self.seeds {
+            write!(f, " {seed}")?;
+        }
+
+        for map in &self.maps {
+            write!(f, "\n\n{map}")?;
+        }
+
+        Ok(())
+    }
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Almanac;
+
+    const DAY: u8 = 5;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        // We split the file up by the blank lines. The seeds come first, and
+        // then every map gets a block of its own. Blocks are never empty.
+        let blocks = aoc_parse::blocks(Self::DAY, input);
+        let (seeds_block, map_blocks) = blocks
+            .split_first()
+            .ok_or_else(|| ParseError::at_end(Self::DAY, input, "a `seeds:` line"))?;
+
+        let seeds_line = &seeds_block[0];
+        let seeds_raw = aoc_parse::labelled(seeds_line, "seeds")?;
+        let seeds: Vec<i64> = aoc_parse::numbers(seeds_line, seeds_raw)?;
+
+        if seeds.is_empty() {
+            return Err(seeds_line.error(seeds_line.end(), "at least one seed"));
+        }
+
+        // Part two reads the seeds as pairs of a start and a length.
+        if !seeds.len().is_multiple_of(2) {
+            return Err(seeds_line.error(seeds_line.end(), "a range length after the last seed"));
+        }
+
+        let lengths_raw = seeds_raw.split_whitespace().skip(1).step_by(2);
+
+        for (pair, length_raw) in seeds.chunks(2).zip(lengths_raw) {
+            if pair[0].checked_add(pair[1]).is_none() {
+                return Err(seeds_line.error(
+                    length_raw,
+                    format!("a range length that keeps the range up to {}", i64::MAX),
+                ));
+            }
+        }
+
+        if let Some(extra_line) = seeds_block.get(1) {
+            return Err(extra_line.error(extra_line.text, "a blank line after the seeds"));
+        }
+
+        let maps = map_blocks
+            .iter()
+            .map(|block| Map::new(&block[0], &block[1..]))
+            .collect::<Result<Vec<Map>, _>>()?;
+
+        Ok(Almanac { seeds, maps })
+    }
+
+    /// Finds the lowest location that any of the seeds map to.
+    fn part_one(input: &Self::Input) -> Option<Answer> {
+        let mut seeds = input.seeds.clone();
+
+        // We take every seed through one map at a time, so that each map's
+        // conversions can be traced together.
+        for map in &input.maps {
+            let _span = debug_span!("map", name = %map.name).entered();
+
+            for seed in seeds.iter_mut() {
+                let converted = map.convert(*seed);
+                trace!(from = *seed, to = converted, "converted a seed");
+
+                *seed = converted;
+            }
+
+            debug!(lowest = seeds.iter().min(), "converted the seeds");
+        }
+
+        Some(seeds.into_iter().min().unwrap().into())
+    }
+
+    /// Finds the lowest location that any of the seeds map to, reading the seeds
+    /// as pairs of a range start and a range length.
+    fn part_two(input: &Self::Input) -> Option<Answer> {
+        let lowest = progress::uncancellable(|progress| input.lowest_location_of_ranges(progress));
+
+        lowest.map(Answer::from)
+    }
+
+    fn solve_with_progress(
+        input: &Self::Input,
+        part: Part,
+        progress: &Progress,
+    ) -> Result<Option<Answer>, Cancelled> {
+        match part {
+            Part::One => Ok(Self::part_one(input)),
+            Part::Two => Ok(input.lowest_location_of_ranges(progress)?.map(Answer::from)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+
+    #[test]
+    fn test_example() {
+        let input = Day5::parse(EXAMPLE).unwrap();
+
+        assert_eq!(input.seeds, [79, 14, 55, 13]);
+        assert_eq!(input.maps.len(), 7);
+        assert_eq!(Day5::part_one(&input), Some(Answer::Signed(35)));
+        assert_eq!(Day5::part_two(&input), Some(Answer::Signed(46)));
+    }
+
+    #[test]
+    fn test_progress() {
+        let input = Day5::parse(EXAMPLE).unwrap();
+
+        let progress = Progress::new();
+        assert_eq!(input.lowest_location_of_ranges(&progress), Ok(Some(46)));
+        assert_eq!(progress.update().done, 27);
+        assert_eq!(progress.update().total, Some(27));
+
+        let progress = Progress::new().with_timeout(std::time::Duration::ZERO);
+        assert_eq!(
+            Day5::solve_with_progress(&input, Part::Two, &progress),
+            Err(Cancelled::TimedOut)
+        );
+    }
+
+    #[test]
+    fn test_windows_input() {
+        // Saved on Windows, with stray spaces on the blank lines between maps.
+        let windows = EXAMPLE.replace("\n\n", "\n  \n").replace('\n', "\r\n");
+
+        assert!(Day5::parse(&windows).is_err());
+        assert_eq!(
+            aoc_common::solution::parse::<Day5>(&windows),
+            Day5::parse(EXAMPLE)
+        );
+    }
+
+    #[test]
+    fn test_map() {
+        let input = Day5::parse(EXAMPLE).unwrap();
+        let seed_to_soil = &input.maps[0];
+
+        assert_eq!(seed_to_soil.convert(79), 81);
+        assert_eq!(seed_to_soil.convert(14), 14);
+        assert_eq!(seed_to_soil.convert(98), 50);
+        assert_eq!(seed_to_soil.convert(100), 100);
+    }
+
+    #[test]
+    fn test_no_seeds() {
+        let error = Day5::parse("seeds:\n\nseed-to-soil map:\n50 98 2").unwrap_err();
+
+        assert_eq!((error.line, error.column), (1, 7));
+    }
+
+    #[test]
+    fn test_odd_seeds() {
+        let error = Day5::parse("seeds: 79 14 55\n\nseed-to-soil map:\n50 98 2").unwrap_err();
+
+        assert_eq!((error.line, error.column), (1, 16));
+        assert_eq!(error.expected, "a range length after the last seed");
+    }
+
+    #[test]
+    fn test_empty_ranges() {
+        let input = Day5::parse("seeds: 79 0\n\nseed-to-soil map:\n50 98 2").unwrap();
+
+        assert_eq!(Day5::part_one(&input), Some(Answer::Signed(0)));
+        assert_eq!(Day5::part_two(&input), None);
+    }
+
+    #[test]
+    fn test_ranges_too_big() {
+        let error =
+            Day5::parse("seeds: 79 14\n\nseed-to-soil map:\n0 9223372036854775807 5").unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.text.as_str()),
+            (4, 23, "5")
+        );
+
+        let error =
+            Day5::parse("seeds: 9223372036854775807 1\n\nseed-to-soil map:\n50 98 2").unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.text.as_str()),
+            (1, 28, "1")
+        );
+
+        // Ranges that end right at the limit are still fine.
+        let input = Day5::parse(
+            "seeds: 9223372036854775806 1\n\nseed-to-soil map:\n0 9223372036854775800 7",
+        )
+        .unwrap();
+        assert_eq!(Day5::part_two(&input), Some(Answer::Signed(6)));
+    }
+
+    #[test]
+    fn test_display() {
+        let input = Day5::parse(EXAMPLE).unwrap();
+
+        assert_eq!(input.maps[0].name, "seed-to-soil");
+        assert_eq!(input.to_string(), EXAMPLE);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let input = Day5::parse(INPUT).unwrap();
+        let printed = input.to_string();
+
+        // The real input is already written the canonical way.
+        assert_eq!(printed.trim_end(), INPUT.trim_end());
+        assert_eq!(Day5::parse(&printed).unwrap(), input);
+    }
+}
@@ -0,0 +1,290 @@
+use aoc_common::{Answer, Line, ParseError, Solution};
+use std::fmt;
+use std::num::IntErrorKind;
+use tracing::debug;
+
+pub const INPUT: &str = include_str!("../input.txt");
+
+/// The longest race we can count the ways to win, since `p * t` has to fit in
+/// an i64 for every time pressed `p` up to the race's time `t`.
+pub const MAX_TIME: i64 = 3_037_000_499;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Race {
+    pub time: i64,
+    pub distance: i64,
+}
+
+impl Race {
+    pub fn races_from_input(input: &str) -> Result<Vec<Self>, ParseError> {
+        let (time_line, distance_line) = Self::time_and_distance_lines(input)?;
+
+        let times_raw = aoc_parse::labelled(&time_line, "Time")?;
+        let times = aoc_parse::numbers(&time_line, times_raw)?;
+
+        for (&time, time_raw) in times.iter().zip(times_raw.split_whitespace()) {
+            Self::check_time(&time_line, time_raw, time)?;
+        }
+        let distances = aoc_parse::numbers(
+            &distance_line,
+            aoc_parse::labelled(&distance_line, "Distance")?,
+        )?;
+
+        // Every race needs both a time and a distance.
+        if times.len() != distances.len() {
+            let (shorter_line, longer_line) = match times.len() < distances.len() {
+                true => (time_line, distance_line),
+                false => (distance_line, time_line),
+            };
+
+            return Err(shorter_line.error(
+                shorter_line.end(),
+                format!("as many numbers as on line {}", longer_line.number),
+            ));
+        }
+
+        Ok(times
+            .into_iter()
+            .zip(distances)
+            .map(|(time, distance)| Self { time, distance })
+            .collect())
+    }
+
+    /// Reads the sheet as one race, with the spaces between the numbers
+    /// ignored. Returns `None` if a number is too big for an i64 once it's
+    /// joined up, even though the races it's made from might be fine.
+    pub fn large_race_from_input(input: &str) -> Result<Option<Self>, ParseError> {
+        let (time_line, distance_line) = Self::time_and_distance_lines(input)?;
+
+        let time = Self::parse_kerned_number(&time_line, "Time")?;
+        let distance = Self::parse_kerned_number(&distance_line, "Distance")?;
+
+        Ok(time
+            .zip(distance)
+            .map(|(time, distance)| Self { time, distance }))
+    }
+
+    /// Grabs the `Time:` and `Distance:` lines from the input.
+    fn time_and_distance_lines(input: &str) -> Result<(Line<'_>, Line<'_>), ParseError> {
+        let mut lines = Line::all(Day6::DAY, input);
+
+        let time_line = lines
+            .next()
+            .ok_or_else(|| ParseError::at_end(Day6::DAY, input, "a `Time:` line"))?;
+        let distance_line = lines
+            .next()
+            .ok_or_else(|| ParseError::at_end(Day6::DAY, input, "a `Distance:` line"))?;
+
+        Ok((time_line, distance_line))
+    }
+
+    /// Turns away a race too long for us to count the ways to win it.
+    fn check_time(line: &Line, raw: &str, time: i64) -> Result<(), ParseError> {
+        match time <= MAX_TIME {
+            true => Ok(()),
+            false => Err(line.error(raw, format!("a time up to {MAX_TIME}"))),
+        }
+    }
+
+    /// Reads the numbers after `label` as one big number, ignoring the spaces
+    /// between them. Returns `None` if it's too big for an i64.
+    fn parse_kerned_number(line: &Line, label: &str) -> Result<Option<i64>, ParseError> {
+        let numbers = aoc_parse::labelled(line, label)?;
+
+        match numbers.split_whitespace().collect::<String>().parse() {
+            Ok(number) => Ok(Some(number)),
+            Err(e)
+                if matches!(
+                    e.kind(),
+                    IntErrorKind::PosOverflow | IntErrorKind::NegOverflow
+                ) =>
+            {
+                Ok(None)
+            }
+            Err(_) => Err(line.error(numbers, "a number split up by spaces")),
+        }
+    }
+
+    pub fn calculate_winning_press_times_amount(&self) -> u64 {
+        // The equation looks like `d < p * (t - p)`,
+        // where d = distance, p = time pressed, and t = time in the race.
+        // This can be written as `d < -p^2 + pt`.
+        // We have d and t available to us.
+        let ways = (0..=self.time)
+            .filter(|p| (-(p * p) + p * self.time) > self.distance)
+            .count() as u64;
+
+        debug!(
+            time = self.time,
+            distance = self.distance,
+            ways,
+            "counted the ways to win a race"
+        );
+
+        ways
+    }
+}
+
+/// Writes the `Time:` and `Distance:` lines for `races`, with each race in a
+/// column wide enough for both of its numbers.
+fn write_races(f: &mut fmt::Formatter<'_>, races: &[Race]) -> fmt::Result {
+    let widths = races
+        .iter()
+        .map(|race| {
+            race.time
+                .to_string()
+                .len()
+                .max(race.distance.to_string().len())
+        })
+        .collect::<Vec<_>>();
+
+    write!(f, "{:<9}", "Time:")?;
+
+    for (race, width) in races.iter().zip(&widths) {
+        write!(f, "  {:>width$}", race.time)?;
+    }
+
+    write!(f, "\n{:<9}", "Distance:")?;
+
+    for (race, width) in races.iter().zip(&widths) {
+        write!(f, "  {:>width$}", race.distance)?;
+    }
+
+    Ok(())
+}
+
+/// Writes a sheet with just this race on it.
+impl fmt::Display for Race {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_races(f, std::slice::from_ref(self))
+    }
+}
+
+/// The sheet of paper from the input. Part one reads it as several races,
+/// while part two reads it as one large race with the spaces ignored.
+#[derive(Debug, PartialEq, Eq)]
+pub struct RaceSheet {
+    pub races: Vec<Race>,
+    /// `None` if its numbers don't fit in an i64, see
+    /// [`Race::large_race_from_input`].
+    pub large_race: Option<Race>,
+}
+
+/// Writes the sheet's races. The large race isn't written, as it's read from
+/// the same numbers.
+impl fmt::Display for RaceSheet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_races(f, &self.races)
+    }
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = RaceSheet;
+
+    const DAY: u8 = 6;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(RaceSheet {
+            races: Race::races_from_input(input)?,
+            large_race: Race::large_race_from_input(input)?,
+        })
+    }
+
+    /// Multiplies together the amount of ways each race can be won. There's no
+    /// answer if the product doesn't fit in a u64.
+    fn part_one(input: &Self::Input) -> Option<Answer> {
+        let winning_press_times_amount = input
+            .races
+            .iter()
+            .map(Race::calculate_winning_press_times_amount)
+            .try_fold(1_u64, u64::checked_mul)?;
+
+        Some(winning_press_times_amount.into())
+    }
+
+    /// Counts the ways to win the single race made by ignoring the spaces
+    /// between the numbers. There's no answer if that race is longer than
+    /// [`MAX_TIME`].
+    fn part_two(input: &Self::Input) -> Option<Answer> {
+        let large_race = input
+            .large_race
+            .as_ref()
+            .filter(|race| race.time <= MAX_TIME)?;
+
+        Some(large_race.calculate_winning_press_times_amount().into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "Time:      7  15   30
+Distance:  9  40  200";
+
+    #[test]
+    fn test_example() {
+        let input = Day6::parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day6::part_one(&input), Some(Answer::Unsigned(288)));
+        assert_eq!(Day6::part_two(&input), Some(Answer::Unsigned(71503)));
+    }
+
+    #[test]
+    fn test_race() {
+        let races = Race::races_from_input(EXAMPLE).unwrap();
+        let winning_times = races
+            .iter()
+            .map(Race::calculate_winning_press_times_amount)
+            .collect::<Vec<_>>();
+
+        assert_eq!(winning_times, [4, 8, 9]);
+
+        let large_race = Race::large_race_from_input(EXAMPLE).unwrap().unwrap();
+
+        assert_eq!((large_race.time, large_race.distance), (71530, 940200));
+    }
+
+    #[test]
+    fn test_mismatched_races() {
+        let error = Day6::parse("Time:      7  15   30\nDistance:  9  40").unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 17));
+    }
+
+    #[test]
+    fn test_time_too_big() {
+        let error = Day6::parse("Time: 9223372036854775807\nDistance: 9").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 7));
+
+        // Part two's race is too long even though each of part one's is fine.
+        let sheet = Day6::parse("Time: 3037000 500\nDistance: 9 9").unwrap();
+        assert_eq!(
+            Day6::part_one(&sheet),
+            Some(Answer::Unsigned(3036999 * 499))
+        );
+        assert_eq!(Day6::part_two(&sheet), None);
+
+        // Once it's joined up, it doesn't even fit in an i64.
+        let sheet = Day6::parse("Time: 1000000000 1000000000\nDistance: 9 9").unwrap();
+        assert_eq!(sheet.large_race, None);
+        assert_eq!(Day6::part_two(&sheet), None);
+    }
+
+    #[test]
+    fn test_display() {
+        let input = Day6::parse(EXAMPLE).unwrap();
+
+        assert_eq!(input.to_string(), EXAMPLE);
+        assert_eq!(input.races[2].to_string(), "Time:       30\nDistance:  200");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let input = Day6::parse(INPUT).unwrap();
+
+        assert_eq!(Day6::parse(&input.to_string()).unwrap(), input);
+    }
+}
@@ -0,0 +1,310 @@
+use aoc_common::{Answer, Line, ParseError, Solution};
+use itertools::Itertools;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt;
+use strum_macros::EnumIter;
+use tracing::trace;
+
+pub const INPUT: &str = include_str!("../input.txt");
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Hand {
+    pub cards: [Card; 5],
+    pub bid: u64,
+    pub hand_type: HandType,
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        //self.height.cmp(&other.height)
+        match self.hand_type.cmp(&other.hand_type) {
+            Ordering::Greater => Ordering::Greater,
+            Ordering::Less => Ordering::Less,
+            Ordering::Equal => {
+                // We compare the cards in order and choose whether
+                // it's greater or less than when the cards are different.
+                for (original_card, other_card) in self.cards.iter().zip(&other.cards) {
+                    match original_card.cmp(other_card) {
+                        Ordering::Greater => return Ordering::Greater,
+                        Ordering::Less => return Ordering::Less,
+                        Ordering::Equal => {}
+                    }
+                }
+
+                // If that all fails (which it shouldn't with this input),
+                // we just return [`Ordering::Equal`]
+                Ordering::Equal
+            }
+        }
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Hand {
+    pub fn new(line: &Line) -> Result<Self, ParseError> {
+        let split = line.text.split_whitespace().collect::<Vec<&str>>();
+
+        let cards_string = split.first().copied().unwrap_or(line.end());
+
+        // We parse the cards from the line and then clone them into an array
+        // so that we know the size.
+        let parsed_cards = cards_string
+            .char_indices()
+            .map(|(i, character)| {
+                Card::try_new(character).map_err(|_| {
+                    let card_string = &cards_string[i..i + character.len_utf8()];
+
+                    line.error(card_string, "a card (`A`, `K`, `Q`, `J`, `T` or `2`-`9`)")
+                })
+            })
+            .collect::<Result<Vec<Card>, _>>()?;
+
+        let mut cards = [Card::A; 5];
+
+        if parsed_cards.len() != cards.len() {
+            return Err(line.error(cards_string, "a hand of exactly 5 cards"));
+        }
+
+        cards.clone_from_slice(&parsed_cards);
+
+        let bid_string = split.get(1).copied().unwrap_or(line.end());
+        let bid = bid_string
+            .parse()
+            .map_err(|_| line.error(bid_string, "a bid"))?;
+
+        let hand_type = HandType::calculate(&cards);
+
+        Ok(Self {
+            cards,
+            bid,
+            hand_type,
+        })
+    }
+}
+
+#[derive(Debug, EnumIter, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+impl fmt::Display for Hand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for card in &self.cards {
+            write!(f, "{card}")?;
+        }
+
+        write!(f, " {}", self.bid)
+    }
+}
+
+impl HandType {
+    pub fn calculate(cards: &[Card; 5]) -> Self {
+        // A hashmap that stores the amount of times we have seen a card in this set.
+        let mut cards_found: HashMap<Card, u64> = HashMap::new();
+
+        for card in cards {
+            let updated_amount = cards_found.get(card).unwrap_or(&0) + 1;
+            cards_found.insert(*card, updated_amount);
+        }
+
+        // We check for the next cases in order of importance.
+        let highest_match_amount = *cards_found.values().max().unwrap();
+        let second_highest_match_amount = cards_found
+            .values()
+            .cloned()
+            .sorted()
+            .rev()
+            .nth(1)
+            .unwrap_or_default();
+
+        if highest_match_amount == 5 {
+            Self::FiveOfAKind
+        } else if highest_match_amount == 4 {
+            Self::FourOfAKind
+        } else if highest_match_amount == 3 && second_highest_match_amount == 2 {
+            Self::FullHouse
+        } else if highest_match_amount == 3 {
+            Self::ThreeOfAKind
+        } else if highest_match_amount == 2 && second_highest_match_amount == 2 {
+            Self::TwoPair
+        } else if highest_match_amount == 2 {
+            Self::OnePair
+        } else {
+            Self::HighCard
+        }
+    }
+}
+
+// Deriving Ord means that the order is defined by how high the variant is listed.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub enum Card {
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    J,
+    Q,
+    K,
+    A,
+}
+
+impl Card {
+    fn try_new(character: char) -> Result<Self, ()> {
+        match character {
+            'A' => Ok(Card::A),
+            'K' => Ok(Card::K),
+            'Q' => Ok(Card::Q),
+            'J' => Ok(Card::J),
+            'T' => Ok(Card::Ten),
+            '9' => Ok(Card::Nine),
+            '8' => Ok(Card::Eight),
+            '7' => Ok(Card::Seven),
+            '6' => Ok(Card::Six),
+            '5' => Ok(Card::Five),
+            '4' => Ok(Card::Four),
+            '3' => Ok(Card::Three),
+            '2' => Ok(Card::Two),
+            _ => Err(()),
+        }
+    }
+}
+
+impl fmt::Display for Card {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let character = match self {
+            Card::A => 'A',
+            Card::K => 'K',
+            Card::Q => 'Q',
+            Card::J => 'J',
+            Card::Ten => 'T',
+            Card::Nine => '9',
+            Card::Eight => '8',
+            Card::Seven => '7',
+            Card::Six => '6',
+            Card::Five => '5',
+            Card::Four => '4',
+            Card::Three => '3',
+            Card::Two => '2',
+        };
+
+        write!(f, "{character}")
+    }
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<Hand>;
+
+    const DAY: u8 = 7;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Line::all(Self::DAY, input)
+            .map(|line| Hand::new(&line))
+            .collect()
+    }
+
+    /// Sums the bid of every hand multiplied by its rank.
+    fn part_one(input: &Self::Input) -> Option<Answer> {
+        // We sort the hands by strength.
+        let hands = input.iter().sorted().collect::<Vec<&Hand>>();
+
+        // There's no answer if a score or the sum doesn't fit in a u64.
+        let sum_of_hand_scores = hands.iter().enumerate().try_fold(0_u64, |sum, (i, hand)| {
+            let rank = i as u64 + 1;
+            trace!(%hand, rank, bid = hand.bid, "ranked a hand");
+
+            sum.checked_add(hand.bid.checked_mul(rank)?)
+        })?;
+
+        Some(sum_of_hand_scores.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+
+    #[test]
+    fn test_example() {
+        let input = Day7::parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day7::part_one(&input), Some(Answer::Unsigned(6440)));
+    }
+
+    #[test]
+    fn test_hand_type() {
+        let hand_types = Day7::parse(EXAMPLE)
+            .unwrap()
+            .iter()
+            .map(|hand| HandType::calculate(&hand.cards))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            hand_types,
+            [
+                HandType::OnePair,
+                HandType::ThreeOfAKind,
+                HandType::TwoPair,
+                HandType::TwoPair,
+                HandType::ThreeOfAKind,
+            ]
+        );
+
+        assert_eq!(HandType::calculate(&[Card::A; 5]), HandType::FiveOfAKind);
+        assert_eq!(
+            HandType::calculate(&[Card::Two, Card::Three, Card::Two, Card::Three, Card::Two]),
+            HandType::FullHouse
+        );
+        assert_eq!(
+            HandType::calculate(&[Card::Two, Card::Three, Card::Four, Card::Five, Card::Six]),
+            HandType::HighCard
+        );
+    }
+
+    #[test]
+    fn test_score_too_big() {
+        let input = Day7::parse("AAAAA 18446744073709551615\n23456 1").unwrap();
+        assert_eq!(Day7::part_one(&input), None);
+
+        // Each score fits, but not the two of them added up.
+        let input = Day7::parse("AAAAA 9223372036854775807\n23456 2").unwrap();
+        assert_eq!(Day7::part_one(&input), None);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let hands = Day7::parse(INPUT).unwrap();
+        let printed = hands
+            .iter()
+            .map(|hand| format!("{hand}\n"))
+            .collect::<String>();
+
+        // The real input is already written the canonical way.
+        assert_eq!(printed.trim_end(), INPUT.trim_end());
+        assert_eq!(Day7::parse(&printed).unwrap(), hands);
+    }
+}
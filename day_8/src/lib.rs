@@ -0,0 +1,439 @@
+use aoc_common::progress::{self, Cancelled, Progress};
+use aoc_common::{Answer, Line, ParseError, Part, Solution};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt;
+use std::str::FromStr;
+use std::{cell::RefCell, rc::Rc};
+use tracing::{debug, trace};
+
+pub const INPUT: &str = include_str!("../input.txt");
+const START_NODE_NAME: &str = "AAA";
+const TERMINATION_NODE_NAME: &str = "ZZZ";
+
+#[derive(Debug)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+impl FromStr for Direction {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "L" => Ok(Self::Left),
+            "R" => Ok(Self::Right),
+            _ => Err(()),
+        }
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Left => write!(f, "L"),
+            Self::Right => write!(f, "R"),
+        }
+    }
+}
+
+/// Represents a `node` from the input file. After the [`Node`] is
+/// initialized, you can just unwrap `left` and `right`. We need to have them
+/// wrapped in `Option`s because the left and right nodes may not exist when
+/// first creating a bunch of `Node`s.
+#[derive(Debug)]
+pub struct Node {
+    pub name: String,
+    pub left: Option<Rc<RefCell<Node>>>,
+    pub right: Option<Rc<RefCell<Node>>>,
+}
+
+impl Node {
+    /// Accepts an input of lines, starting from the first "node line".
+    /// Returns the first starting node that connects to everything else.
+    pub fn starting_node_from_lines(lines: &[Line]) -> Result<Rc<RefCell<Self>>, ParseError> {
+        // We put all the nodes in here. They may not all be finished.
+        let mut nodes: Vec<Rc<RefCell<Self>>> = Vec::new();
+
+        // We create a hashmap of the string name and the node value.
+        let mut name_node_map: HashMap<String, Rc<RefCell<Self>>> = HashMap::new();
+
+        // We keep a hashmap of node names, and the names of nodes
+        // to add to the node after we are done with parsing.
+        // Format: `HashMap<name, (line, left_name, right_name)>`. We keep the line
+        // around so that we can point at it if a name doesn't exist.
+        let mut missing_nodes: HashMap<String, (&Line, &str, &str)> = HashMap::new();
+
+        // We do the parsing here.
+        for line in lines {
+            let (name, left_name, right_name) = split_node_line(line)?;
+            let name = name.to_string();
+
+            // We go ahead and write down the names of the nodes that
+            // we'll need.
+            missing_nodes.insert(name.clone(), (line, left_name, right_name));
+
+            let node = Rc::new(RefCell::new(Self {
+                name: name.clone(),
+                left: None,
+                right: None,
+            }));
+
+            // We add this node to all the maps and vectors above
+            nodes.push(Rc::clone(&node));
+            name_node_map.insert(name, Rc::clone(&node));
+        }
+
+        // We now "attach" all the nodes to each other.
+        for node in &nodes {
+            let (line, left_name, right_name) = missing_nodes[&node.borrow().name];
+
+            let find_node = |name: &str| {
+                name_node_map
+                    .get(name)
+                    .map(Rc::clone)
+                    .ok_or_else(|| line.error(name, "the name of a node in the network"))
+            };
+
+            let left_node = find_node(left_name)?;
+            let right_node = find_node(right_name)?;
+
+            node.borrow_mut().left = Some(left_node);
+            node.borrow_mut().right = Some(right_node);
+        }
+
+        // We only need to return the first node (the AAA node). The rest are still in memory
+        // because they are wrapped in `Rc`.
+
+        let starting_node = nodes
+            .iter()
+            .find(|node| node.borrow().name == START_NODE_NAME)
+            .ok_or_else(|| {
+                let after_last_line = lines.last().map_or(1, |line| line.number + 1);
+
+                ParseError::new(
+                    Day8::DAY,
+                    after_last_line,
+                    1,
+                    "",
+                    format!("a node named `{START_NODE_NAME}`"),
+                )
+            })?;
+
+        Ok(Rc::clone(starting_node))
+    }
+
+    /// Traverses from the starting node until `TERMINATION_NODE_NAME` is reached.
+    /// After this, the program returns the amount of steps it took. The steps
+    /// are reported to `progress` every time we go through the directions.
+    pub fn steps_until_termination(
+        starting_node: Rc<RefCell<Self>>,
+        directions: &[Direction],
+        progress: &Progress,
+    ) -> Result<u64, Cancelled> {
+        let mut traversal = Traversal::new(starting_node, directions);
+
+        while traversal.step() {
+            if traversal.direction_index == 0 {
+                progress.advance(directions.len() as u64)?;
+            }
+        }
+
+        Ok(traversal.steps)
+    }
+}
+
+/// A walk through the network that follows one direction at a time, going
+/// back to the first direction when they run out.
+pub struct Traversal<'a> {
+    pub current_node: Rc<RefCell<Node>>,
+    pub directions: &'a [Direction],
+    /// The index of the direction the next step follows.
+    pub direction_index: usize,
+    pub steps: u64,
+}
+
+impl<'a> Traversal<'a> {
+    pub fn new(starting_node: Rc<RefCell<Node>>, directions: &'a [Direction]) -> Self {
+        Self {
+            current_node: starting_node,
+            directions,
+            direction_index: 0,
+            steps: 0,
+        }
+    }
+
+    /// Follows the next direction. Returns true if another step can be made,
+    /// and false once we've reached `TERMINATION_NODE_NAME`.
+    pub fn step(&mut self) -> bool {
+        let next_node = match self.directions[self.direction_index] {
+            Direction::Left => Rc::clone(self.current_node.borrow().left.as_ref().unwrap()),
+            Direction::Right => Rc::clone(self.current_node.borrow().right.as_ref().unwrap()),
+        };
+
+        self.current_node = next_node;
+        self.direction_index = (self.direction_index + 1) % self.directions.len();
+
+        // Go ahead and increment steps before checking for termination.
+        self.steps += 1;
+
+        trace!(steps = self.steps, node = %self.current_node.borrow().name, "took a step");
+
+        if self.direction_index == 0 {
+            debug!(
+                steps = self.steps,
+                node = %self.current_node.borrow().name,
+                "went through every direction"
+            );
+        }
+
+        !self.is_finished()
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.current_node.borrow().name == TERMINATION_NODE_NAME
+    }
+}
+
+/// Writes the node like `AAA = (BBB, CCC)`. Only nodes that have been attached
+/// to their left and right nodes can be written.
+impl fmt::Display for Node {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name_of = |side: &Option<Rc<RefCell<Node>>>| {
+            side.as_ref()
+                .map(|node| node.borrow().name.clone())
+                .ok_or(fmt::Error)
+        };
+
+        write!(
+            f,
+            "{} = ({}, {})",
+            self.name,
+            name_of(&self.left)?,
+            name_of(&self.right)?
+        )
+    }
+}
+
+/// Splits a node line like `AAA = (BBB, CCC)` into the names of the node
+/// and its left and right nodes.
+fn split_node_line<'a>(line: &Line<'a>) -> Result<(&'a str, &'a str, &'a str), ParseError> {
+    let record = aoc_parse::record(line)?;
+
+    for name in [record.key, record.left, record.right] {
+        if name.len() != 3 {
+            return Err(line.error(name, "a three character node name"));
+        }
+    }
+
+    Ok((record.key, record.left, record.right))
+}
+
+/// Parses the directions from the first line, and returns them along with
+/// the node lines that come after the blank line.
+fn split_network_lines(input: &str) -> Result<(Vec<Direction>, Vec<Line<'_>>), ParseError> {
+    let mut blocks = aoc_parse::blocks(Day8::DAY, input).into_iter();
+
+    let directions_block = blocks
+        .next()
+        .ok_or_else(|| ParseError::at_end(Day8::DAY, input, "a line of directions"))?;
+
+    if let Some(extra_line) = directions_block.get(1) {
+        return Err(extra_line.error(extra_line.text, "a blank line after the directions"));
+    }
+
+    let node_lines = blocks
+        .next()
+        .ok_or_else(|| ParseError::at_end(Day8::DAY, input, "a blank line followed by nodes"))?;
+
+    if let Some(extra_block) = blocks.next() {
+        let extra_line = extra_block[0];
+
+        return Err(extra_line.error(extra_line.text, "no blank lines between the nodes"));
+    }
+
+    Ok((parse_directions(&directions_block[0])?, node_lines))
+}
+
+/// Parses the directions from the first line of the input.
+pub fn parse_directions(line: &Line) -> Result<Vec<Direction>, ParseError> {
+    if line.text.is_empty() {
+        return Err(line.error(line.end(), "at least one direction"));
+    }
+
+    line.text
+        .char_indices()
+        .map(|(i, c)| {
+            let direction = &line.text[i..i + c.len_utf8()];
+
+            Direction::from_str(direction).map_err(|_| line.error(direction, "`L` or `R`"))
+        })
+        .collect()
+}
+
+/// The parsed network: the directions to follow, and the node we start on.
+#[derive(Debug)]
+pub struct Network {
+    pub directions: Vec<Direction>,
+    pub starting_node: Rc<RefCell<Node>>,
+}
+
+impl Network {
+    /// Every node that can be reached from the starting node, in the order
+    /// they're first found.
+    pub fn reachable_nodes(&self) -> Vec<Rc<RefCell<Node>>> {
+        let mut seen = HashSet::from([self.starting_node.borrow().name.clone()]);
+        let mut queue = VecDeque::from([Rc::clone(&self.starting_node)]);
+        let mut nodes = Vec::new();
+
+        while let Some(node) = queue.pop_front() {
+            for side in [&node.borrow().left, &node.borrow().right]
+                .into_iter()
+                .flatten()
+            {
+                if seen.insert(side.borrow().name.clone()) {
+                    queue.push_back(Rc::clone(side));
+                }
+            }
+
+            nodes.push(node);
+        }
+
+        nodes
+    }
+}
+
+/// Writes the directions and the nodes that can be reached from the starting
+/// node. Nodes that can't be reached were never kept, so they aren't written.
+impl fmt::Display for Network {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for direction in &self.directions {
+            write!(f, "{direction}")?;
+        }
+
+        writeln!(f)?;
+
+        for node in self.reachable_nodes() {
+            write!(f, "\n{}", node.borrow())?;
+        }
+
+        Ok(())
+    }
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Network;
+
+    const DAY: u8 = 8;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let (directions, node_lines) = split_network_lines(input)?;
+
+        let starting_node = Node::starting_node_from_lines(&node_lines)?;
+
+        Ok(Network {
+            directions,
+            starting_node,
+        })
+    }
+
+    /// Counts the steps it takes to get from `AAA` to `ZZZ`.
+    fn part_one(input: &Self::Input) -> Option<Answer> {
+        progress::uncancellable(|progress| Self::solve_with_progress(input, Part::One, progress))
+    }
+
+    fn solve_with_progress(
+        input: &Self::Input,
+        part: Part,
+        progress: &Progress,
+    ) -> Result<Option<Answer>, Cancelled> {
+        match part {
+            Part::One => {
+                let steps_until_termination = Node::steps_until_termination(
+                    Rc::clone(&input.starting_node),
+                    &input.directions,
+                    progress,
+                )?;
+
+                Ok(Some(steps_until_termination.into()))
+            }
+            Part::Two => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        let input = Day8::parse(
+            "RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)",
+        )
+        .unwrap();
+
+        assert_eq!(Day8::part_one(&input), Some(Answer::Unsigned(2)));
+
+        // The directions repeat when they run out.
+        let input = Day8::parse(
+            "LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)",
+        )
+        .unwrap();
+
+        assert_eq!(Day8::part_one(&input), Some(Answer::Unsigned(6)));
+
+        let mut traversal = Traversal::new(Rc::clone(&input.starting_node), &input.directions);
+
+        for _ in 0..3 {
+            assert!(traversal.step());
+        }
+
+        assert_eq!(traversal.current_node.borrow().name, "BBB");
+        assert_eq!((traversal.direction_index, traversal.steps), (0, 3));
+    }
+
+    #[test]
+    fn test_parse_directions() {
+        let line = |text| Line {
+            day: Day8::DAY,
+            number: 1,
+            text,
+        };
+
+        assert!(matches!(
+            parse_directions(&line("LRL")).unwrap()[..],
+            [Direction::Left, Direction::Right, Direction::Left]
+        ));
+        assert_eq!(parse_directions(&line("LRXL")).unwrap_err().column, 3);
+        assert!(parse_directions(&line("")).is_err());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let printed = Day8::parse(INPUT).unwrap().to_string();
+        let mut lines = printed.lines();
+
+        assert_eq!(lines.next(), INPUT.lines().next());
+        assert_eq!(lines.next(), Some(""));
+        assert!(lines.next().unwrap().starts_with("AAA = ("));
+        // Every node is written just as it is in the input.
+        assert!(lines.all(|line| INPUT.lines().any(|input_line| input_line == line)));
+
+        assert_eq!(Day8::parse(&printed).unwrap().to_string(), printed);
+    }
+}
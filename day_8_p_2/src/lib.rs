@@ -0,0 +1,481 @@
+//! DISCLAIMER: this does not work and it is just here for me to work on it
+//! when I feel like it again.
+
+use aoc_common::progress::{self, Cancelled, Progress};
+use aoc_common::{Answer, Line, ParseError, Part, Solution};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::{cell::RefCell, rc::Rc};
+use tracing::{debug, trace};
+
+pub const INPUT: &str = include_str!("../input.txt");
+const START_NODE_NAME: &str = "AAA";
+const TERMINATION_NODE_NAME: &str = "ZZZ";
+
+#[derive(Debug)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+impl FromStr for Direction {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "L" => Ok(Self::Left),
+            "R" => Ok(Self::Right),
+            _ => Err(()),
+        }
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Left => write!(f, "L"),
+            Self::Right => write!(f, "R"),
+        }
+    }
+}
+
+/// Represents a `node` from the input file. After the [`Node`] is
+/// initialized, you can just unwrap `left` and `right`. We need to have them
+/// wrapped in `Option`s because the left and right nodes may not exist when
+/// first creating a bunch of `Node`s.
+#[derive(Debug)]
+pub struct Node {
+    pub name: String,
+    pub left: Option<Rc<RefCell<Node>>>,
+    pub right: Option<Rc<RefCell<Node>>>,
+}
+
+impl Node {
+    /// Accepts an input of lines, starting from the first "node line".
+    /// Returns a vector of the nodes, each wrapped in `Rc<RefCell<T>>`.
+    pub fn nodes_from_lines(lines: &[Line]) -> Result<Vec<Rc<RefCell<Self>>>, ParseError> {
+        // We put all the nodes in here. They may not all be finished.
+        let mut nodes: Vec<Rc<RefCell<Self>>> = Vec::new();
+
+        // We create a hashmap of the string name and the node value.
+        let mut name_node_map: HashMap<String, Rc<RefCell<Self>>> = HashMap::new();
+
+        // We keep a hashmap of node names, and the names of nodes
+        // to add to the node after we are done with parsing.
+        // Format: `HashMap<name, (line, left_name, right_name)>`. We keep the line
+        // around so that we can point at it if a name doesn't exist.
+        let mut missing_nodes: HashMap<String, (&Line, &str, &str)> = HashMap::new();
+
+        // We do the parsing here.
+        for line in lines {
+            let (name, left_name, right_name) = split_node_line(line)?;
+            let name = name.to_string();
+
+            // We go ahead and write down the names of the nodes that
+            // we'll need.
+            missing_nodes.insert(name.clone(), (line, left_name, right_name));
+
+            let node = Rc::new(RefCell::new(Self {
+                name: name.clone(),
+                left: None,
+                right: None,
+            }));
+
+            // We add this node to all the maps and vectors above
+            nodes.push(Rc::clone(&node));
+            name_node_map.insert(name, Rc::clone(&node));
+        }
+
+        // We now "attach" all the nodes to each other.
+        for node in &nodes {
+            let (line, left_name, right_name) = missing_nodes[&node.borrow().name];
+
+            let find_node = |name: &str| {
+                name_node_map
+                    .get(name)
+                    .map(Rc::clone)
+                    .ok_or_else(|| line.error(name, "the name of a node in the network"))
+            };
+
+            let left_node = find_node(left_name)?;
+            let right_node = find_node(right_name)?;
+
+            node.borrow_mut().left = Some(left_node);
+            node.borrow_mut().right = Some(right_node);
+        }
+
+        /* Rc::clone(
+            nodes
+                .iter()
+                .find(|node| node.borrow().name == START_NODE_NAME)
+                .unwrap(),
+        ) */
+
+        Ok(nodes)
+    }
+
+    /// Traverses from the starting node until `TERMINATION_NODE_NAME` is reached.
+    /// After this, the program returns the amount of steps it took. THe "original"
+    /// naming scheme is just because we have two versions of this. The steps
+    /// are reported to `progress` every time we go through the directions.
+    pub fn steps_until_original_termination(
+        starting_node: Rc<RefCell<Self>>,
+        directions: &[Direction],
+        progress: &Progress,
+    ) -> Result<u64, Cancelled> {
+        let mut current_node = Rc::clone(&starting_node);
+        let mut steps = 0;
+
+        loop {
+            for direction in directions {
+                let next_node = match direction {
+                    Direction::Left => Rc::clone(current_node.borrow().left.as_ref().unwrap()),
+                    Direction::Right => Rc::clone(current_node.borrow().right.as_ref().unwrap()),
+                };
+
+                current_node = next_node;
+
+                // Go ahead and increment steps before checking for termination.
+                steps += 1;
+
+                trace!(steps, node = %current_node.borrow().name, "took a step");
+
+                if current_node.borrow().name == TERMINATION_NODE_NAME {
+                    return Ok(steps);
+                }
+            }
+
+            debug!(
+                steps,
+                node = %current_node.borrow().name,
+                "went through every direction"
+            );
+
+            progress.advance(directions.len() as u64)?;
+        }
+    }
+
+    /// Traverses the nodes until the last character is the same as the
+    /// last character in TERMINATION_NODE_NAME for all nodes. Reports to
+    /// `progress` like [`Self::steps_until_original_termination`].
+    ///
+    /// Each node is taken to its end once, and the nodes all end together
+    /// after the least common multiple of those steps. That's `None` if it
+    /// doesn't fit in a `u64`.
+    pub fn steps_until_alternative_termination(
+        starting_nodes: Vec<Rc<RefCell<Self>>>,
+        directions: &[Direction],
+        progress: &Progress,
+    ) -> Result<Option<u64>, Cancelled> {
+        let mut nodes_remaining = starting_nodes;
+        let mut steps_needed_for_each = Vec::new();
+        let mut current_steps = 0;
+
+        loop {
+            for direction in directions {
+                // Go ahead and increment current steps before checking for termination.
+                current_steps += 1;
+
+                nodes_remaining.retain_mut(|node| {
+                    // We go ahead and set the current node to the new node.
+                    let next_node = match direction {
+                        Direction::Left => Rc::clone(node.borrow().left.as_ref().unwrap()),
+                        Direction::Right => Rc::clone(node.borrow().right.as_ref().unwrap()),
+                    };
+
+                    // We record whether we need to remove the new node from remaining nodes.
+                    let terminator_met = next_node.borrow().name.chars().nth(2)
+                        == TERMINATION_NODE_NAME.chars().nth(2);
+
+                    *node = next_node;
+
+                    // If we do need to remove it, we record the steps it took to
+                    // get here
+                    if terminator_met {
+                        debug!(
+                            steps = current_steps,
+                            node = %node.borrow().name,
+                            "a node reached its end"
+                        );
+                        steps_needed_for_each.push(current_steps);
+                    }
+
+                    !terminator_met
+                });
+
+                // Once every node has reached its end, we're done.
+                if nodes_remaining.is_empty() {
+                    return Ok(steps_needed_for_each
+                        .into_iter()
+                        .try_fold(1, least_common_multiple));
+                }
+            }
+
+            debug!(
+                steps = current_steps,
+                remaining = nodes_remaining.len(),
+                "went through every direction"
+            );
+
+            progress.advance(directions.len() as u64)?;
+        }
+    }
+}
+
+/// Writes the node like `AAA = (BBB, CCC)`. Only nodes that have been attached
+/// to their left and right nodes can be written.
+impl fmt::Display for Node {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name_of = |side: &Option<Rc<RefCell<Node>>>| {
+            side.as_ref()
+                .map(|node| node.borrow().name.clone())
+                .ok_or(fmt::Error)
+        };
+
+        write!(
+            f,
+            "{} = ({}, {})",
+            self.name,
+            name_of(&self.left)?,
+            name_of(&self.right)?
+        )
+    }
+}
+
+/// The least common multiple of `a` and `b`, or `None` if it doesn't fit in
+/// a `u64`.
+fn least_common_multiple(a: u64, b: u64) -> Option<u64> {
+    let (mut x, mut y) = (a, b);
+
+    while y != 0 {
+        (x, y) = (y, x % y);
+    }
+
+    if x == 0 {
+        return Some(0);
+    }
+
+    (a / x).checked_mul(b)
+}
+
+/// Splits a node line like `AAA = (BBB, CCC)` into the names of the node
+/// and its left and right nodes.
+fn split_node_line<'a>(line: &Line<'a>) -> Result<(&'a str, &'a str, &'a str), ParseError> {
+    let record = aoc_parse::record(line)?;
+
+    for name in [record.key, record.left, record.right] {
+        if name.len() != 3 {
+            return Err(line.error(name, "a three character node name"));
+        }
+    }
+
+    Ok((record.key, record.left, record.right))
+}
+
+/// Parses the directions from the first line, and returns them along with
+/// the node lines that come after the blank line.
+fn split_network_lines(input: &str) -> Result<(Vec<Direction>, Vec<Line<'_>>), ParseError> {
+    let mut blocks = aoc_parse::blocks(Day8::DAY, input).into_iter();
+
+    let directions_block = blocks
+        .next()
+        .ok_or_else(|| ParseError::at_end(Day8::DAY, input, "a line of directions"))?;
+
+    if let Some(extra_line) = directions_block.get(1) {
+        return Err(extra_line.error(extra_line.text, "a blank line after the directions"));
+    }
+
+    let node_lines = blocks
+        .next()
+        .ok_or_else(|| ParseError::at_end(Day8::DAY, input, "a blank line followed by nodes"))?;
+
+    if let Some(extra_block) = blocks.next() {
+        let extra_line = extra_block[0];
+
+        return Err(extra_line.error(extra_line.text, "no blank lines between the nodes"));
+    }
+
+    Ok((parse_directions(&directions_block[0])?, node_lines))
+}
+
+/// Parses the directions from the first line of the input.
+pub fn parse_directions(line: &Line) -> Result<Vec<Direction>, ParseError> {
+    if line.text.is_empty() {
+        return Err(line.error(line.end(), "at least one direction"));
+    }
+
+    line.text
+        .char_indices()
+        .map(|(i, c)| {
+            let direction = &line.text[i..i + c.len_utf8()];
+
+            Direction::from_str(direction).map_err(|_| line.error(direction, "`L` or `R`"))
+        })
+        .collect()
+}
+
+/// The parsed network: the directions to follow, and every node in it.
+#[derive(Debug)]
+pub struct Network {
+    pub directions: Vec<Direction>,
+    pub nodes: Vec<Rc<RefCell<Node>>>,
+}
+
+impl fmt::Display for Network {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for direction in &self.directions {
+            write!(f, "{direction}")?;
+        }
+
+        writeln!(f)?;
+
+        for node in &self.nodes {
+            write!(f, "\n{}", node.borrow())?;
+        }
+
+        Ok(())
+    }
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Network;
+
+    const DAY: u8 = 8;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let (directions, node_lines) = split_network_lines(input)?;
+
+        let nodes = Node::nodes_from_lines(&node_lines)?;
+
+        Ok(Network { directions, nodes })
+    }
+
+    /// Counts the steps it takes to get from `AAA` to `ZZZ`.
+    fn part_one(input: &Self::Input) -> Option<Answer> {
+        progress::uncancellable(|progress| Self::solve_with_progress(input, Part::One, progress))
+    }
+
+    /// Counts the steps it takes for every node ending in `A` to land on a node
+    /// ending in `Z` at the same time.
+    fn part_two(input: &Self::Input) -> Option<Answer> {
+        progress::uncancellable(|progress| Self::solve_with_progress(input, Part::Two, progress))
+    }
+
+    fn solve_with_progress(
+        input: &Self::Input,
+        part: Part,
+        progress: &Progress,
+    ) -> Result<Option<Answer>, Cancelled> {
+        match part {
+            Part::One => {
+                // We currently only need to hold onto the first node (the AAA
+                // node). Inputs made for part two, like its example, don't
+                // have one, so part one can't be solved for them.
+                let Some(starting_node) = input
+                    .nodes
+                    .iter()
+                    .find(|node| node.borrow().name == START_NODE_NAME)
+                    .map(Rc::clone)
+                else {
+                    return Ok(None);
+                };
+
+                let steps_until_termination = Node::steps_until_original_termination(
+                    starting_node,
+                    &input.directions,
+                    progress,
+                )?;
+
+                Ok(Some(steps_until_termination.into()))
+            }
+            Part::Two => {
+                let nodes_that_end_with_a = input
+                    .nodes
+                    .iter()
+                    .filter(|node| {
+                        node.borrow().name.chars().nth(2) == START_NODE_NAME.chars().nth(2)
+                    })
+                    .map(Rc::clone)
+                    .collect::<Vec<_>>();
+
+                let steps_until_alternative_termination =
+                    Node::steps_until_alternative_termination(
+                        nodes_that_end_with_a,
+                        &input.directions,
+                        progress,
+                    )?;
+
+                Ok(steps_until_alternative_termination.map(Answer::from))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let input = Day8::parse(
+            "LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)",
+        )
+        .unwrap();
+
+        assert_eq!(Day8::part_two(&input), Some(Answer::Unsigned(6)));
+
+        // There's no `AAA` to start part one from.
+        assert_eq!(Day8::part_one(&input), None);
+    }
+
+    #[test]
+    fn test_cycles_share_a_factor() {
+        // The first ghost lands on a Z every 2 steps and the second every 4, so
+        // they first line up after 4 steps.
+        let input = Day8::parse(
+            "L
+
+11A = (11B, XXX)
+11B = (11Z, XXX)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, XXX)
+22C = (22D, XXX)
+22D = (22Z, XXX)
+22Z = (22B, XXX)
+XXX = (XXX, XXX)",
+        )
+        .unwrap();
+
+        assert_eq!(Day8::part_two(&input), Some(Answer::Unsigned(4)));
+    }
+
+    #[test]
+    fn test_least_common_multiple() {
+        assert_eq!(least_common_multiple(4, 6), Some(12));
+        assert_eq!(least_common_multiple(7, 1), Some(7));
+        assert_eq!(least_common_multiple(u64::MAX, u64::MAX), Some(u64::MAX));
+        assert_eq!(least_common_multiple(u64::MAX, 2), None);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let printed = Day8::parse(INPUT).unwrap().to_string();
+
+        // The real input is already written the canonical way.
+        assert_eq!(printed.trim_end(), INPUT.trim_end());
+        assert_eq!(Day8::parse(&printed).unwrap().to_string(), printed);
+    }
+}
@@ -0,0 +1,12 @@
+#![no_main]
+
+use aoc_common::Part;
+use aoc_fuzz::{parse, solve};
+use day_1_p_2::Day1;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Some(input) = parse::<Day1>(data) {
+        solve::<Day1>(&input, &[Part::Two]);
+    }
+});
@@ -0,0 +1,12 @@
+#![no_main]
+
+use aoc_common::Part;
+use aoc_fuzz::{parse, solve};
+use day_4::Day4;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Some(input) = parse::<Day4>(data) {
+        solve::<Day4>(&input, &[Part::One, Part::Two]);
+    }
+});
@@ -0,0 +1,23 @@
+#![no_main]
+
+use aoc_common::Part;
+use aoc_fuzz::{parse, solve};
+use day_5::Day5;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Some(input) = parse::<Day5>(data) {
+        // Part 2 expands every seed range into a `Vec`, so it's only solved
+        // when the ranges are short enough to keep each run quick.
+        let seeds = input
+            .seeds
+            .chunks(2)
+            .map(|pair| pair[1].clamp(0, 100_001))
+            .sum::<i64>();
+
+        match seeds <= 100_000 {
+            true => solve::<Day5>(&input, &[Part::One, Part::Two]),
+            false => solve::<Day5>(&input, &[Part::One]),
+        }
+    }
+});
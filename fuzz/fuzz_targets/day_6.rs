@@ -0,0 +1,18 @@
+#![no_main]
+
+use aoc_common::Part;
+use aoc_fuzz::{parse, solve};
+use day_6::Day6;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Some(sheet) = parse::<Day6>(data) {
+        // Both parts try every way of holding the button, so only short races
+        // are solved to keep each run quick.
+        let races = sheet.races.iter().chain(&sheet.large_race);
+
+        if races.map(|race| race.time).all(|time| time <= 100_000) {
+            solve::<Day6>(&sheet, &[Part::One, Part::Two]);
+        }
+    }
+});
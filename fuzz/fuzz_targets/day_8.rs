@@ -0,0 +1,11 @@
+#![no_main]
+
+use aoc_fuzz::parse;
+use day_8::Day8;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    // Following the directions never finishes if they don't lead to `ZZZ`, so
+    // only the parser is fuzzed.
+    parse::<Day8>(data);
+});
@@ -0,0 +1,10 @@
+#![no_main]
+
+use aoc_fuzz::parse;
+use day_9::Day9;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    // Part 1 doesn't finish yet, so only the parser is fuzzed.
+    parse::<Day9>(data);
+});
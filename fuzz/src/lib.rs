@@ -0,0 +1,26 @@
+//! Helpers shared by the fuzz targets in `fuzz_targets`. Every target feeds
+//! its data to one day's parser, and to the solver too when the solver is sure
+//! to finish quickly on whatever parses.
+
+use aoc_common::{Part, Solution};
+
+/// Parses `data` as the input for `S`. Data that isn't UTF-8 is skipped, and
+/// parse errors are rendered so that the error reporting is fuzzed as well.
+pub fn parse<S: Solution>(data: &[u8]) -> Option<S::Input> {
+    let input = std::str::from_utf8(data).ok()?;
+
+    match S::parse(input) {
+        Ok(parsed) => Some(parsed),
+        Err(e) => {
+            e.render(input);
+            None
+        }
+    }
+}
+
+/// Solves each of `parts` for an input that parsed.
+pub fn solve<S: Solution>(input: &S::Input, parts: &[Part]) {
+    for &part in parts {
+        S::solve(input, part);
+    }
+}